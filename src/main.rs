@@ -1,15 +1,12 @@
-mod parts;
-use parts::capacitors::Capacitor;
+use parts_lu::parts::capacitors::Capacitor;
 use std::str::FromStr;
-use crate::parts::capacitors::Capacitance;
-use crate::parts::{EIAInchCode, IECMetricCode, RatedVoltage};
 
 fn main() {
     let cap = Capacitor::from_str("GRM033R61A224ME90#").unwrap();
     println!("{}", cap);
     println!("{:#}", cap);
     println!("{:?}", cap);
-    // let c = Capacitance::PicoFarads(1_500_000);
+    // let c = Capacitance::from_picofarads(1_500_000);
     // println!("{:#}", c);
 
     // let ic = EIAInchCode::_0603;
@@ -0,0 +1,290 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Dielectric, Height, RatedVoltage, Tolerance};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// Plated Ni and Sn termination
+    Standard,
+    /// Flexible termination (FLEXITERM)
+    Flexiterm,
+}
+
+/// AVX part numbers start with the EIA inch code
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0201,
+    _0402,
+    _0603,
+    _0805,
+    _1206,
+    _1210,
+    _1808,
+    _1812,
+    _1825,
+    _2220,
+    _2225,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0201 => "0201",
+            _0402 => "0402",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1808 => "1808",
+            _1812 => "1812",
+            _1825 => "1825",
+            _2220 => "2220",
+            _2225 => "2225",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0201" => Ok(_0201),
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "0805" => Ok(_0805),
+            "1206" => Ok(_1206),
+            "1210" => Ok(_1210),
+            "1808" => Ok(_1808),
+            "1812" => Ok(_1812),
+            "1825" => Ok(_1825),
+            "2220" => Ok(_2220),
+            "2225" => Ok(_2225),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _0201 => { EIAInchCode::_0201 },
+            _0402 => { EIAInchCode::_0402 },
+            _0603 => { EIAInchCode::_0603 },
+            _0805 => { EIAInchCode::_0805 },
+            _1206 => { EIAInchCode::_1206 },
+            _1210 => { EIAInchCode::_1210 },
+            _1808 => { EIAInchCode::_1808 },
+            _1812 => { EIAInchCode::_1812 },
+            _1825 => { EIAInchCode::_1825 },
+            _2220 => { EIAInchCode::_2220 },
+            _2225 => { EIAInchCode::_2225 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_0201 => { Some(_0201) },
+            EIAInchCode::_0402 => { Some(_0402) },
+            EIAInchCode::_0603 => { Some(_0603) },
+            EIAInchCode::_0805 => { Some(_0805) },
+            EIAInchCode::_1206 => { Some(_1206) },
+            EIAInchCode::_1210 => { Some(_1210) },
+            EIAInchCode::_1808 => { Some(_1808) },
+            EIAInchCode::_1812 => { Some(_1812) },
+            EIAInchCode::_1825 => { Some(_1825) },
+            EIAInchCode::_2220 => { Some(_2220) },
+            EIAInchCode::_2225 => { Some(_2225) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        '4' => Ok(DC_4V),
+        '6' => Ok(DC_6V3),
+        'Z' => Ok(DC_10V),
+        'Y' => Ok(DC_16V),
+        '3' => Ok(DC_25V),
+        'D' => Ok(DC_35V),
+        '5' => Ok(DC_50V),
+        '1' => Ok(DC_100V),
+        '2' => Ok(DC_200V),
+        'V' => Ok(DC_250V),
+        '7' => Ok(DC_500V),
+        'A' => Ok(DC_1kV),
+        'G' => Ok(DC_2kV),
+        'H' => Ok(DC_3kV),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+pub fn parse_dielectric(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'A' => Ok(C0G),
+        'C' => Ok(X7R),
+        'D' => Ok(X5R),
+        'F' => Ok(X8R),
+        'G' => Ok(Y5V),
+        'W' => Ok(X6S),
+        'Z' => Ok(X7S),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+/// Failure rate: A = not applicable, 4 = automotive
+pub fn parse_failure_rate(code: char) -> Result<Grade, Error> {
+    match code {
+        'A' => Ok(Grade::Commercial),
+        '4' => Ok(Grade::Automotive),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+pub fn parse_termination(code: char) -> Result<Series, Error> {
+    match code {
+        'T' => Ok(Series::Standard),
+        'Z' => Ok(Series::Flexiterm),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        '1' => Ok(Packaging::Reel(Reel::new(180, Tape::Embossed))),
+        '2' => Ok(Packaging::Reel(Reel::new(180, Tape::Paper))),
+        '3' => Ok(Packaging::Reel(Reel::new(330, Tape::Embossed))),
+        '4' => Ok(Packaging::Reel(Reel::new(330, Tape::Paper))),
+        '7' => Ok(Packaging::BulkCase),
+        '9' => Ok(Packaging::Bulk),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// 0603YC104KAT2A
+/// 0603 | Y | C | 104 | K | A | T | 2 | A
+/// size, voltage, dielectric, capacitance, tolerance, failure rate, termination, packaging,
+/// special code
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    // The dielectric letter in the 6th position tells AVX apart from other inch code first
+    // numbering schemes (Walsin 0603B104K500CT)
+    if !part_number.is_ascii() || part_number.len() < 6
+        || !part_number[0..4].bytes().all(|c| c.is_ascii_digit())
+        || !part_number.as_bytes()[5].is_ascii_alphabetic() {
+        return Err(Error::UnknownSeries);
+    }
+    if part_number.len() < 13 {
+        return Err(Error::InsufficientData);
+    }
+    let code = part_number.as_bytes();
+    let dimensions = Dimensions::from_str(&part_number[0..4])?;
+    let voltage = parse_voltage(code[4] as char)?;
+    let dielectric = parse_dielectric(code[5] as char)?;
+    let capacitance = parse_capacitance(&part_number[6..9])?;
+    let tolerance = parse_tolerance(&capacitance, code[9] as char)?;
+    let grade = parse_failure_rate(code[10] as char)?;
+    let series = parse_termination(code[11] as char)?;
+    let packaging = parse_packaging(code[12] as char)?;
+    let features = Features {
+        soft_termination: matches!(series, Series::Flexiterm),
+        ..Features::default()
+    };
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Avx(series),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade,
+        features,
+        specification: None,
+        other: String::from(&part_number[13..])
+    })
+}
+
+/// Creates an AVX part number, inverse of parse.
+/// Packaging defaults to 7" paper reel, special code is standard (A).
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let voltage = find_code(&['4', '6', 'Z', 'Y', '3', 'D', '5', '1', '2', 'V', '7', 'A', 'G', 'H'],
+        &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    let dielectric = find_code(&['A', 'C', 'D', 'F', 'G', 'W', 'Z'], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let failure_rate = find_code(&['A', '4'], &capacitor.grade, parse_failure_rate, Attribute::Grade)?;
+    let termination = if capacitor.features.soft_termination { 'Z' } else { 'T' };
+    let packaging = find_code(&['2', '1', '4', '3', '7', '9'], &capacitor.packaging.normalized(), parse_packaging, Attribute::Packaging)
+        .unwrap_or('2');
+    Ok(format!("{}{}{}{}{}{}{}{}A",
+        dimensions.code(),
+        voltage,
+        dielectric,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        failure_rate,
+        termination,
+        packaging
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let c = parse("0603YC104KAT2A").unwrap();
+        assert!(matches!(c.series, crate::parts::capacitors::Series::Avx(Series::Standard)));
+        assert_eq!(c.dimensions, EIAInchCode::_0603);
+        assert_eq!(c.voltage, RatedVoltage::DC_16V);
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(c.capacitance, Capacitance::from_nanofarads(100));
+        assert_eq!(c.tolerance, Tolerance::PM10);
+        assert_eq!(c.grade, Grade::Commercial);
+        assert_eq!(c.packaging, Packaging::Reel(Reel::new(180, Tape::Paper)));
+        assert_eq!(c.other, "A");
+        let c = parse("06035C104K4Z2A").unwrap();
+        assert_eq!(c.grade, Grade::Automotive);
+        assert!(c.features.soft_termination);
+    }
+
+    #[test]
+    fn invalid_codes() {
+        assert!(matches!(parse("0603YC104KAT"), Err(Error::InsufficientData)));
+        assert!(matches!(parse("0603XC104KAT2A"), Err(Error::WrongVoltageCode)));
+        assert!(matches!(parse("0603YQ104KAT2A"), Err(Error::WrongDielectricCode)));
+        assert!(matches!(parse("0603YC104KBT2A"), Err(Error::WrongProductCode)));
+        assert!(matches!(parse("0603YC104KAX2A"), Err(Error::WrongProductCode)));
+        assert!(matches!(parse("0603YC104KAT8A"), Err(Error::WrongPackagingCode)));
+        assert!(matches!(parse("0603B104K500CT"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["0603YC104KAT2A", "06035C104KAT2A", "06035C104K4T2A", "06035C104K4Z2A", "1206ZD106KAT2A", "0402YC103KAT4A"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+use crate::parts::{Height, Tolerance, capacitors::{Attribute, Capacitor}};
+
+/// Result of checking a substitute against the original part
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Compatibility {
+    /// Substitute is worse than the original
+    pub violated: Vec<Attribute>,
+    /// Substitute differs from the original but is at least as good
+    pub relaxed: Vec<Attribute>,
+    /// Not enough data to decide, e.g. height missing from the part number
+    pub unknown: Vec<Attribute>,
+}
+
+impl Compatibility {
+    pub fn is_compatible(&self) -> bool {
+        self.violated.is_empty()
+    }
+}
+
+/// Checks if `substitute` can be placed instead of `original` without a board or design change.
+/// Footprint must match, the rest must be equal or better: same or higher grade, every construction
+/// feature of the original, lower height, more stable dielectric, higher rated voltage, same nominal
+/// capacitance and a tolerance range within the original's. A nominal and a maximum height do not
+/// compare and are reported as unknown.
+pub fn can_replace(substitute: &Capacitor, original: &Capacitor) -> Compatibility {
+    let mut result = Compatibility::default();
+
+    if !substitute.grade.covers(&original.grade) {
+        result.violated.push(Attribute::Grade);
+    } else if substitute.grade != original.grade {
+        result.relaxed.push(Attribute::Grade);
+    }
+
+    if !substitute.features.covers(&original.features) {
+        result.violated.push(Attribute::Features);
+    } else if substitute.features != original.features {
+        result.relaxed.push(Attribute::Features);
+    }
+
+    if substitute.dimensions != original.dimensions {
+        result.violated.push(Attribute::Dimensions);
+    }
+
+    // a nominal thickness says nothing about the upper limit, only heights of the same kind compare
+    let heights = match (substitute.max_height, original.max_height) {
+        (Height::Nominal(s), Height::Nominal(o)) | (Height::Maximum(s), Height::Maximum(o)) => Some((s, o)),
+        _ => None
+    };
+    match heights {
+        Some((s, o)) if s > o => result.violated.push(Attribute::Height),
+        Some((s, o)) if s < o => result.relaxed.push(Attribute::Height),
+        Some(_) => {},
+        None => result.unknown.push(Attribute::Height),
+    }
+
+    if substitute.dielectric != original.dielectric {
+        if substitute.dielectric.can_replace(&original.dielectric) {
+            result.relaxed.push(Attribute::Dielectric);
+        } else {
+            result.violated.push(Attribute::Dielectric);
+        }
+    }
+
+    if !substitute.voltage.at_least(original.voltage) {
+        result.violated.push(Attribute::Voltage);
+    } else if substitute.voltage > original.voltage {
+        result.relaxed.push(Attribute::Voltage);
+    }
+
+    if substitute.capacitance != original.capacitance {
+        result.violated.push(Attribute::Capacitance);
+    }
+    let (s_min, s_max) = substitute.tolerance.bounds(&substitute.capacitance);
+    let (o_min, o_max) = original.tolerance.bounds(&original.capacitance);
+    if substitute.tolerance == Tolerance::Unknown || original.tolerance == Tolerance::Unknown {
+        result.unknown.push(Attribute::Tolerance);
+    } else if s_min < o_min || s_max > o_max {
+        result.violated.push(Attribute::Tolerance);
+    } else if s_min > o_min || s_max < o_max {
+        result.relaxed.push(Attribute::Tolerance);
+    }
+
+    result
+}
+
+impl Capacitor {
+    /// Checks if this part can replace `other`, see [can_replace]
+    pub fn can_replace(&self, other: &Capacitor) -> Compatibility {
+        can_replace(self, other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parts::Length;
+    use std::str::FromStr;
+
+    #[test]
+    fn substitutes() {
+        let original = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        let check = |pn: &str| Capacitor::from_str(pn).unwrap().can_replace(&original);
+        assert_eq!(check("CL10B104KB8NNNC"), Compatibility::default());
+        assert_eq!(check("C1608X7R1E104K080AA").violated, [Attribute::Voltage]);
+        let higher_voltage = check("GRM188R72A104KA35D");
+        assert!(higher_voltage.is_compatible());
+        assert_eq!(higher_voltage.relaxed, [Attribute::Voltage]);
+        assert_eq!(check("CL10A106KP8NNNC").violated,
+            [Attribute::Dielectric, Attribute::Voltage, Attribute::Capacitance, Attribute::Tolerance]);
+    }
+
+    #[test]
+    fn unknown_tolerance() {
+        let nfm = Capacitor::from_str("NFM18PC104R1C3D").unwrap();
+        let grm = Capacitor::from_str("GRM188R71C104KA01D").unwrap();
+        assert_eq!(nfm.can_replace(&nfm).unknown, [Attribute::Height, Attribute::Tolerance]);
+        assert!(grm.can_replace(&nfm).unknown.contains(&Attribute::Tolerance));
+        assert!(!nfm.can_replace(&grm).violated.contains(&Attribute::Tolerance));
+    }
+
+    #[test]
+    fn height_kinds() {
+        let original = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        let mut substitute = original.clone();
+        substitute.max_height = Height::Maximum(Length::from_micrometres(900));
+        assert_eq!(substitute.can_replace(&original).unknown, [Attribute::Height]);
+        let mut original = original;
+        original.max_height = Height::Maximum(Length::from_micrometres(950));
+        assert_eq!(substitute.can_replace(&original).relaxed, [Attribute::Height]);
+        substitute.max_height = Height::Maximum(Length::from_micrometres(1000));
+        assert_eq!(substitute.can_replace(&original).violated, [Attribute::Height]);
+    }
+
+    #[test]
+    fn grade_and_features() {
+        let grm = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        let gcm = Capacitor::from_str("GCM188R71H104KA57D").unwrap();
+        let gcj = Capacitor::from_str("GCJ188R71H104KA12D").unwrap();
+        assert_eq!(grm.can_replace(&gcm).violated, [Attribute::Grade]);
+        assert_eq!(gcm.can_replace(&grm).relaxed, [Attribute::Grade]);
+        assert_eq!(gcm.can_replace(&gcj).violated, [Attribute::Features]);
+        assert_eq!(gcj.can_replace(&gcm).relaxed, [Attribute::Features]);
+    }
+}
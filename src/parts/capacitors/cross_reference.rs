@@ -0,0 +1,163 @@
+use crate::parts::{Height, RatedVoltage, Tolerance, capacitors::{Capacitor, Error, Manufacturer, Packaging,
+    avx, kemet, murata, samsung, taiyo_yuden, tdk, vishay, walsin, yageo}};
+
+/// Equivalent part from another manufacturer
+#[derive(Debug, Clone)]
+pub struct Candidate {
+    pub manufacturer: Manufacturer,
+    pub part_number: String,
+    pub voltage: RatedVoltage,
+    pub tolerance: Tolerance,
+}
+
+type Encoder = fn(&Capacitor) -> Result<String, Error>;
+type Parser = fn(&str) -> Result<Capacitor, Error>;
+
+const MANUFACTURERS: [(Manufacturer, Encoder, Parser); 9] = [
+    (Manufacturer::Murata, murata::encode, murata::parse),
+    (Manufacturer::Samsung, samsung::encode, samsung::parse),
+    (Manufacturer::Tdk, tdk::encode, tdk::parse),
+    (Manufacturer::Yageo, yageo::encode, yageo::parse),
+    (Manufacturer::Kemet, kemet::encode, kemet::parse),
+    (Manufacturer::Avx, avx::encode, avx::parse),
+    (Manufacturer::TaiyoYuden, taiyo_yuden::encode, taiyo_yuden::parse),
+    (Manufacturer::Walsin, walsin::encode, walsin::parse),
+    (Manufacturer::Vishay, vishay::encode, vishay::parse),
+];
+
+/// Tolerances tried as replacements
+const TOLERANCES: [Tolerance; 12] = {
+    use Tolerance::*;
+    [PM05, PM1, PM2, PM5, PM10, PM20, Percent { minus: 20, plus: 80 }, PM0pF05, PM0pF1, PM0pF25, PM0pF5, PM1pF]
+};
+
+/// Candidate must have every feature of the original, and be automotive if the original is
+fn covers(candidate: &Capacitor, original: &Capacitor) -> bool {
+    candidate.features.covers(&original.features) && candidate.grade.covers(&original.grade)
+}
+
+/// Finds the closest equivalent part of every other supported manufacturer: same size, dielectric
+/// and capacitance, equal or higher rated voltage and equal or tighter tolerance. The lowest voltage
+/// and then the loosest tolerance is preferred. Manufacturers that encode the thickness only
+/// yield a candidate when the original height has a code in their table. An unknown original height
+/// is taken as the standard thickness of the size, the body width (0.8mm for 0603). Part numbers that cannot be
+/// fully encoded, e.g. a Murata catalogue prefix without specification and packaging codes, are not
+/// orderable and are skipped.
+pub fn cross_reference(capacitor: &Capacitor) -> Vec<Candidate> {
+    let own = capacitor.series.manufacturer();
+    let nominal = capacitor.capacitance;
+    let (min, max) = capacitor.tolerance.bounds(&nominal);
+
+    let mut voltages: Vec<RatedVoltage> = RatedVoltage::STANDARD.iter()
+        .chain(std::iter::once(&capacitor.voltage))
+        .filter(|v| v.at_least(capacitor.voltage))
+        .copied()
+        .collect();
+    voltages.sort();
+    voltages.dedup();
+
+    let mut tolerances: Vec<Tolerance> = TOLERANCES.iter()
+        .chain(std::iter::once(&capacitor.tolerance))
+        .filter(|t| {
+            let (t_min, t_max) = t.bounds(&nominal);
+            t_min >= min && t_max <= max
+        })
+        .copied()
+        .collect();
+    tolerances.sort_by_key(|t| {
+        let (t_min, t_max) = t.bounds(&nominal);
+        std::cmp::Reverse(t_max.femtofarads() - t_min.femtofarads())
+    });
+    tolerances.dedup();
+
+    let max_height = match capacitor.max_height {
+        Height::Unknown => Height::Nominal(capacitor.dimensions.body_size().width),
+        height => height
+    };
+
+    let mut candidates = Vec::new();
+    for (manufacturer, encode, parse) in MANUFACTURERS {
+        if manufacturer == own {
+            continue;
+        }
+        let found = voltages.iter()
+            .flat_map(|v| tolerances.iter().map(move |t| (*v, *t)))
+            .find_map(|(voltage, tolerance)| {
+                let replacement = Capacitor {
+                    voltage,
+                    tolerance,
+                    max_height,
+                    specification: None,
+                    ..capacitor.clone()
+                };
+                let part_number = encode(&replacement).ok()?;
+                let decoded = parse(&part_number).ok()?;
+                let complete = decoded.packaging != Packaging::Unspecified;
+                (complete && covers(&decoded, capacitor)).then_some(Candidate { manufacturer, part_number, voltage, tolerance })
+            });
+        candidates.extend(found);
+    }
+    candidates
+}
+
+impl Capacitor {
+    /// Equivalent parts from other manufacturers, see [cross_reference]
+    pub fn cross_reference(&self) -> Vec<Candidate> {
+        cross_reference(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parts::{Length, capacitors::Grade};
+    use std::str::FromStr;
+
+    #[test]
+    fn every_other_manufacturer() {
+        let original = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        let part_numbers: Vec<String> = original.cross_reference().into_iter()
+            .map(|c| {
+                assert_eq!((c.voltage, c.tolerance), (RatedVoltage::DC_50V, Tolerance::PM10));
+                c.part_number
+            })
+            .collect();
+        assert_eq!(part_numbers, ["CL10B104KB8NNNC", "C1608X7R1H104K080AA", "CC0603KRX7R9BB104", "C0603C104K5RACTU",
+            "06035C104KAT2A", "UMK107B7104KA-T", "0603B104K500CT", "VJ0603Y104KXAAC"]);
+    }
+
+    #[test]
+    fn complete_part_numbers_only() {
+        for pn in &["CL10B104KB8NNNC", "C0603C104K5RACAUTO", "CC0603KRX7R9BB104"] {
+            let original = Capacitor::from_str(pn).unwrap();
+            for candidate in original.cross_reference() {
+                assert_ne!(candidate.manufacturer, Manufacturer::Murata, "{}", candidate.part_number);
+                let decoded = Capacitor::from_str(&candidate.part_number).unwrap();
+                assert_ne!(decoded.packaging, Packaging::Unspecified, "{}", candidate.part_number);
+            }
+        }
+    }
+
+    #[test]
+    fn unknown_height() {
+        for (pn, height) in &[("CC0603KRX7R9BB104", 800), ("GRM32XR71H105KA01L", 2500)] {
+            let original = Capacitor::from_str(pn).unwrap();
+            assert_eq!(original.max_height, Height::Unknown);
+            let candidates = original.cross_reference();
+            for manufacturer in &[Manufacturer::Samsung, Manufacturer::Tdk] {
+                let candidate = candidates.iter().find(|c| c.manufacturer == *manufacturer).unwrap();
+                let decoded = Capacitor::from_str(&candidate.part_number).unwrap();
+                assert_eq!(decoded.max_height.length(), Some(Length::from_micrometres(*height)), "{}", candidate.part_number);
+            }
+        }
+    }
+
+    #[test]
+    fn automotive_only() {
+        let original = Capacitor::from_str("C0603C104K5RACAUTO").unwrap();
+        for candidate in original.cross_reference() {
+            let decoded = Capacitor::from_str(&candidate.part_number).unwrap();
+            assert_eq!(decoded.grade, Grade::Automotive, "{}", candidate.part_number);
+        }
+    }
+}
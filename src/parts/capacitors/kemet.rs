@@ -0,0 +1,311 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, find_code}, Dielectric, Height, RatedVoltage, Tolerance};
+use strum_macros::EnumString;
+use std::str::FromStr;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// Standard termination
+    C,
+    /// Flexible termination (FT-CAP)
+    X,
+}
+
+/// KEMET uses the EIA inch code directly
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0201,
+    _0402,
+    _0603,
+    _0805,
+    _1206,
+    _1210,
+    _1808,
+    _1812,
+    _1825,
+    _2220,
+    _2225,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0201 => "0201",
+            _0402 => "0402",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1808 => "1808",
+            _1812 => "1812",
+            _1825 => "1825",
+            _2220 => "2220",
+            _2225 => "2225",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0201" => Ok(_0201),
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "0805" => Ok(_0805),
+            "1206" => Ok(_1206),
+            "1210" => Ok(_1210),
+            "1808" => Ok(_1808),
+            "1812" => Ok(_1812),
+            "1825" => Ok(_1825),
+            "2220" => Ok(_2220),
+            "2225" => Ok(_2225),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _0201 => { EIAInchCode::_0201 },
+            _0402 => { EIAInchCode::_0402 },
+            _0603 => { EIAInchCode::_0603 },
+            _0805 => { EIAInchCode::_0805 },
+            _1206 => { EIAInchCode::_1206 },
+            _1210 => { EIAInchCode::_1210 },
+            _1808 => { EIAInchCode::_1808 },
+            _1812 => { EIAInchCode::_1812 },
+            _1825 => { EIAInchCode::_1825 },
+            _2220 => { EIAInchCode::_2220 },
+            _2225 => { EIAInchCode::_2225 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_0201 => { Some(_0201) },
+            EIAInchCode::_0402 => { Some(_0402) },
+            EIAInchCode::_0603 => { Some(_0603) },
+            EIAInchCode::_0805 => { Some(_0805) },
+            EIAInchCode::_1206 => { Some(_1206) },
+            EIAInchCode::_1210 => { Some(_1210) },
+            EIAInchCode::_1808 => { Some(_1808) },
+            EIAInchCode::_1812 => { Some(_1812) },
+            EIAInchCode::_1825 => { Some(_1825) },
+            EIAInchCode::_2220 => { Some(_2220) },
+            EIAInchCode::_2225 => { Some(_2225) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        '7' => Ok(DC_4V),
+        '9' => Ok(DC_6V3),
+        '8' => Ok(DC_10V),
+        '4' => Ok(DC_16V),
+        '3' => Ok(DC_25V),
+        '6' => Ok(DC_35V),
+        '5' => Ok(DC_50V),
+        '1' => Ok(DC_100V),
+        '2' => Ok(DC_200V),
+        'A' => Ok(DC_250V),
+        'C' => Ok(DC_500V),
+        'B' => Ok(DC_630V),
+        'D' => Ok(DC_1kV),
+        'F' => Ok(CustomDC(1_500_000)),
+        'G' => Ok(DC_2kV),
+        'H' => Ok(DC_3kV),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+pub fn parse_dielectric(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'G' => Ok(C0G),
+        'H' => Ok(X8R),
+        'P' => Ok(X5R),
+        'R' => Ok(X7R),
+        'V' => Ok(Y5V),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+/// Failure rate: A = not applicable, the only code of commercial and automotive parts
+pub fn parse_failure_rate(code: char) -> Result<(), Error> {
+    match code {
+        'A' => Ok(()),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+/// Termination finish: C = 100% matte tin, L = tin/lead
+pub fn parse_termination(code: char) -> Result<char, Error> {
+    match code {
+        'C' | 'L' => Ok(code),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+/// Packaging suffix after the termination finish, AUTO marks the AEC-Q200 variant
+pub fn parse_packaging(code: &str) -> Result<(Packaging, Grade), Error> {
+    let (grade, code) = if let Some(code) = code.strip_prefix("AUTO") {
+        (Grade::Automotive, code)
+    } else {
+        (Grade::Commercial, code)
+    };
+    let packaging = match code {
+        "" if grade == Grade::Automotive => Packaging::Reel(Reel::new(180, Tape::Unspecified)),
+        "" => Packaging::Bulk,
+        "TU" => Packaging::Reel(Reel::new(180, Tape::Unspecified)),
+        "7411" => Packaging::Reel(Reel::new(330, Tape::Unspecified)),
+        "7210" => Packaging::Reel(Reel::new(330, Tape::Unspecified)),
+        _ => return Err(Error::WrongPackagingCode)
+    };
+    Ok((packaging, grade))
+}
+
+/// C0805C104K5RACTU
+/// C | 0805 | C | 104 | K | 5 | R | A | C | TU
+/// ceramic, size, series, capacitance, tolerance, voltage, dielectric, failure rate, termination,
+/// packaging
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    if !part_number.is_ascii() || part_number.len() < 6 || !part_number.starts_with('C')
+        || !part_number[1..5].bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Error::UnknownSeries);
+    }
+    let series = Series::from_str(&part_number[5..6]).map_err(|_| Error::UnknownSeries)?;
+    if part_number.len() < 14 {
+        return Err(Error::InsufficientData);
+    }
+    let code = part_number.as_bytes();
+    let dimensions = Dimensions::from_str(&part_number[1..5])?;
+    let capacitance = parse_capacitance(&part_number[6..9])?;
+    let tolerance = parse_tolerance(&capacitance, code[9] as char)?;
+    let voltage = parse_voltage(code[10] as char)?;
+    let dielectric = parse_dielectric(code[11] as char)?;
+    parse_failure_rate(code[12] as char)?;
+    parse_termination(code[13] as char)?;
+    let (packaging, grade) = parse_packaging(&part_number[14..])?;
+    let features = Features {
+        soft_termination: matches!(series, Series::X),
+        ..Features::default()
+    };
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Kemet(series),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade,
+        features,
+        specification: None,
+        other: String::from(&part_number[12..])
+    })
+}
+
+/// Inverse of parse_capacitance, KEMET uses the 9 and 8 multipliers below 10pF
+pub fn capacitance_code(capacitance: &Capacitance) -> Result<String, Error> {
+    let ff = capacitance.femtofarads();
+    match ff {
+        0..=9_999 if ff.is_multiple_of(100) => Ok(format!("{:02}9", ff / 100)),
+        0..=999 if ff.is_multiple_of(10) => Ok(format!("{:02}8", ff / 10)),
+        0..=9_999 => Err(Error::NoCode(Attribute::Capacitance)),
+        _ => crate::parts::capacitors::capacitance_code(capacitance)
+    }
+}
+
+/// Creates a KEMET part number, inverse of parse.
+/// Failure rate is not applicable (A), termination is kept for KEMET parts and 100% tin (C) otherwise,
+/// packaging is 7" reel, AUTO for automotive.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    let series = if capacitor.features.soft_termination { Series::X } else { Series::C };
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let voltage = find_code(&['7', '9', '8', '4', '3', '6', '5', '1', '2', 'A', 'C', 'B', 'D', 'F', 'G', 'H'],
+        &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    let dielectric = find_code(&['G', 'H', 'P', 'R', 'V'], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let packaging = match capacitor.grade {
+        Grade::Commercial => "TU",
+        Grade::Automotive => "AUTO"
+    };
+    let termination = match capacitor.series {
+        crate::parts::capacitors::Series::Kemet(_) => capacitor.other.get(1..2).unwrap_or("C"),
+        _ => "C"
+    };
+    Ok(format!("C{}{:?}{}{}{}{}A{}{}",
+        dimensions.code(),
+        series,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        voltage,
+        dielectric,
+        termination,
+        packaging
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let c = parse("C0805C104K5RACTU").unwrap();
+        assert!(matches!(c.series, crate::parts::capacitors::Series::Kemet(Series::C)));
+        assert_eq!(c.dimensions, EIAInchCode::_0805);
+        assert_eq!(c.capacitance, Capacitance::from_nanofarads(100));
+        assert_eq!(c.tolerance, Tolerance::PM10);
+        assert_eq!(c.voltage, RatedVoltage::DC_50V);
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(c.grade, Grade::Commercial);
+        assert_eq!(c.other, "ACTU");
+        let c = parse("C0603X104K5RACAUTO").unwrap();
+        assert!(c.features.soft_termination);
+        assert_eq!(c.grade, Grade::Automotive);
+        assert_eq!(parse("C0402C109C5GACTU").unwrap().capacitance, Capacitance::from_picofarads(1));
+    }
+
+    #[test]
+    fn invalid_codes() {
+        assert!(matches!(parse("C0805C104K5RA"), Err(Error::InsufficientData)));
+        assert!(matches!(parse("C0805C104K5RBCTU"), Err(Error::WrongProductCode)));
+        assert!(matches!(parse("C0805C104K5RAXTU"), Err(Error::WrongProductCode)));
+        assert!(matches!(parse("C0805C104K5QACTU"), Err(Error::WrongDielectricCode)));
+        assert!(matches!(parse("C0805C104K0RACTU"), Err(Error::WrongVoltageCode)));
+        assert!(matches!(parse("C0805C104K5RACXX"), Err(Error::WrongPackagingCode)));
+        assert!(matches!(parse("C0805Q104K5RACTU"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["C0805C104K5RACTU", "C0603C104K5RACAUTO", "C0603X104K5RACAUTO", "C1206C105K3RALTU", "C0402C109C5GACTU"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
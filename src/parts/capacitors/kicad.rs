@@ -0,0 +1,129 @@
+use crate::parts::{EIAInchCode, capacitors::{Capacitor, Error}};
+use std::str::FromStr;
+
+/// Footprint from the KiCad standard library, None for sizes it does not have
+#[allow(clippy::just_underscores_and_digits)]
+pub fn footprint(capacitor: &Capacitor) -> Option<&'static str> {
+    use EIAInchCode::*;
+    match capacitor.dimensions {
+        _01005 => Some("Capacitor_SMD:C_01005_0402Metric"),
+        _0201 => Some("Capacitor_SMD:C_0201_0603Metric"),
+        _0402 => Some("Capacitor_SMD:C_0402_1005Metric"),
+        _0504 => Some("Capacitor_SMD:C_0504_1310Metric"),
+        _0603 => Some("Capacitor_SMD:C_0603_1608Metric"),
+        _0805 => Some("Capacitor_SMD:C_0805_2012Metric"),
+        _1206 => Some("Capacitor_SMD:C_1206_3216Metric"),
+        _1210 => Some("Capacitor_SMD:C_1210_3225Metric"),
+        _1806 => Some("Capacitor_SMD:C_1806_4516Metric"),
+        _1812 => Some("Capacitor_SMD:C_1812_4532Metric"),
+        _1825 => Some("Capacitor_SMD:C_1825_4564Metric"),
+        _2010 => Some("Capacitor_SMD:C_2010_5025Metric"),
+        _2220 => Some("Capacitor_SMD:C_2220_5750Metric"),
+        _2225 => Some("Capacitor_SMD:C_2225_5664Metric"),
+        _2512 => Some("Capacitor_SMD:C_2512_6332Metric"),
+        _3640 => Some("Capacitor_SMD:C_3640_9110Metric"),
+        _ => None
+    }
+}
+
+/// Symbol fields in KiCad order: Reference, Value (100n), Footprint, Datasheet, then MPN,
+/// Manufacturer, Voltage, Dielectric and Tolerance. Footprint is empty when there is none.
+pub fn fields(part_number: &str, capacitor: &Capacitor) -> Vec<(&'static str, String)> {
+    let value = capacitor.capacitance.to_string();
+    vec![
+        ("Reference", String::from("C")),
+        ("Value", String::from(value.trim_end_matches('F'))),
+        ("Footprint", String::from(footprint(capacitor).unwrap_or_default())),
+        ("Datasheet", String::new()),
+        ("MPN", String::from(part_number)),
+        ("Manufacturer", capacitor.series.manufacturer().to_string()),
+        ("Voltage", capacitor.voltage.to_string()),
+        ("Dielectric", capacitor.dielectric.to_string()),
+        ("Tolerance", capacitor.tolerance.to_string()),
+    ]
+}
+
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Unpolarized capacitor symbol named after the part number, fields after Value are hidden
+fn symbol(part_number: &str, capacitor: &Capacitor) -> String {
+    let name = quote(part_number);
+    let mut s = format!("  (symbol {} (pin_numbers hide) (pin_names (offset 0.254)) (in_bom yes) (on_board yes)\n", name);
+    for (id, (field, value)) in fields(part_number, capacitor).iter().enumerate() {
+        let (at, effects) = match id {
+            0 => ("0.635 2.54 0", "(justify left)"),
+            1 => ("0.635 -2.54 0", "(justify left)"),
+            _ => ("0 0 0", "hide"),
+        };
+        s += &format!("    (property {} {} (id {}) (at {}) (effects (font (size 1.27 1.27)) {}))\n",
+            quote(field), quote(value), id, at, effects);
+    }
+    s += &format!("    (symbol {}\n", quote(&format!("{}_0_1", part_number)));
+    for y in &["-0.762", "0.762"] {
+        s += &format!("      (polyline (pts (xy -2.032 {y}) (xy 2.032 {y})) (stroke (width 0.508) (type default)) (fill (type none)))\n", y = y);
+    }
+    s += "    )\n";
+    s += &format!("    (symbol {}\n", quote(&format!("{}_1_1", part_number)));
+    for (number, at) in &[("1", "0 3.81 270"), ("2", "0 -3.81 90")] {
+        s += &format!("      (pin passive line (at {}) (length 2.794) (name \"~\" (effects (font (size 1.27 1.27)))) (number \"{}\" (effects (font (size 1.27 1.27)))))\n",
+            at, number);
+    }
+    s += "    )\n  )\n";
+    s
+}
+
+/// `.kicad_sym` library with one symbol per part number, duplicates are skipped.
+/// Fails on the first part number that does not decode.
+pub fn symbol_library(part_numbers: &[&str]) -> Result<String, Error> {
+    let mut library = String::from("(kicad_symbol_lib (version 20211014) (generator parts-lu)\n");
+    let mut done: Vec<&str> = Vec::new();
+    for part_number in part_numbers {
+        if done.contains(part_number) {
+            continue;
+        }
+        let capacitor = Capacitor::from_str(part_number)?;
+        library += &symbol(part_number, &capacitor);
+        done.push(part_number);
+    }
+    library += ")\n";
+    Ok(library)
+}
+
+impl Capacitor {
+    /// KiCad symbol fields, see [fields]
+    pub fn kicad_fields(&self, part_number: &str) -> Vec<(&'static str, String)> {
+        fields(part_number, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grm_fields() {
+        let grm = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        assert_eq!(grm.kicad_fields("GRM188R71H104KA93D"), [
+            ("Reference", "C".to_string()),
+            ("Value", "100n".to_string()),
+            ("Footprint", "Capacitor_SMD:C_0603_1608Metric".to_string()),
+            ("Datasheet", String::new()),
+            ("MPN", "GRM188R71H104KA93D".to_string()),
+            ("Manufacturer", "Murata".to_string()),
+            ("Voltage", "50V".to_string()),
+            ("Dielectric", "X7R".to_string()),
+            ("Tolerance", "±10%".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn library() {
+        let library = symbol_library(&["GRM188R71H104KA93D", "CL21A106KOQNNNE"]).unwrap();
+        assert!(library.starts_with("(kicad_symbol_lib (version 20211014) (generator parts-lu)\n"));
+        assert!(library.contains("(property \"Footprint\" \"Capacitor_SMD:C_0805_2012Metric\""));
+        assert!(library.contains("(property \"Manufacturer\" \"Samsung Electro-Mechanics\""));
+        assert!(symbol_library(&["XYZ123"]).is_err());
+    }
+}
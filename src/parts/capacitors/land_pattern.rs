@@ -0,0 +1,145 @@
+use crate::parts::{Height, Length, package::BodySize, capacitors::Capacitor};
+
+/// IPC-7351 density level, the letter ends the footprint name
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Density {
+    /// Most, largest fillets for hand soldering and rework
+    M,
+    /// Nominal
+    N,
+    /// Least, high density boards
+    L,
+}
+
+/// Pads and courtyard of a two terminal chip, all dimensions centred on the part
+#[derive(Debug, Clone, PartialEq)]
+pub struct LandPattern {
+    /// IPC-7351 name, CAPC1608X90N
+    pub name: String,
+    /// Pad size along the body axis
+    pub pad_length: Length,
+    pub pad_width: Length,
+    /// Pad centre to pad centre
+    pub pitch: Length,
+    pub courtyard_length: Length,
+    pub courtyard_width: Length,
+}
+
+/// Fabrication and placement tolerance in mm
+const FABRICATION: f64 = 0.05;
+const PLACEMENT: f64 = 0.025;
+/// Pad dimensions are rounded to 0.05mm
+const ROUND_OFF: f64 = 0.05;
+
+/// Toe, heel and side fillet goals and courtyard excess in mm, IPC-7351B tables 3-5 and 3-6
+fn goals(density: Density, small: bool) -> (f64, f64, f64, f64) {
+    match (density, small) {
+        (Density::M, false) => (0.55, -0.05, 0.05, 0.5),
+        (Density::N, false) => (0.35, -0.05, 0.0, 0.25),
+        (Density::L, false) => (0.15, -0.05, -0.05, 0.1),
+        (Density::M, true) => (0.3, 0.0, 0.05, 0.2),
+        (Density::N, true) => (0.2, 0.0, 0.0, 0.15),
+        (Density::L, true) => (0.1, 0.0, 0.0, 0.1),
+    }
+}
+
+/// Maximum body height, nominal thickness is assumed to have the width tolerance
+fn max_height(height: &Height, body: &BodySize) -> Length {
+    match height {
+        Height::Maximum(h) => *h,
+        Height::Nominal(h) => Length::from_micrometres(h.micrometres() + body.width_tolerance.micrometres()),
+        Height::Unknown => body.max_thickness,
+    }
+}
+
+/// CAPC, body length and width truncated to 0.1mm, X, maximum height in 0.01mm and the density level
+pub fn ipc7351_name(capacitor: &Capacitor, density: Density) -> String {
+    let body = capacitor.dimensions.body_size();
+    let height = max_height(&capacitor.max_height, &body);
+    format!("CAPC{:02}{:02}X{}{:?}",
+        body.length.micrometres() / 100,
+        body.width.micrometres() / 100,
+        (height.micrometres() + 5) / 10,
+        density
+    )
+}
+
+fn mm(length: Length) -> f64 {
+    length.millimetres()
+}
+
+fn length(mm: f64) -> Length {
+    Length::from_micrometres((mm * 1000.0).round() as u32)
+}
+
+fn round_up(mm: f64) -> f64 {
+    (mm / ROUND_OFF - 1e-9).ceil() * ROUND_OFF
+}
+
+fn round_down(mm: f64) -> f64 {
+    (mm / ROUND_OFF + 1e-9).floor() * ROUND_OFF
+}
+
+/// IPC-7351 land pattern from the package body size. The terminal band is not in the package table
+/// and is taken as 10 to 30% of the body length, at most 0.8mm, which covers common MLCCs.
+pub fn land_pattern(capacitor: &Capacitor, density: Density) -> LandPattern {
+    let body = capacitor.dimensions.body_size();
+    let (toe, heel, side, courtyard) = goals(density, body.length < Length::from_micrometres(1600));
+    let (l_min, l_max) = (mm(body.length) - mm(body.length_tolerance), mm(body.max_length()));
+    let (w_min, w_max) = (mm(body.width) - mm(body.width_tolerance), mm(body.max_width()));
+    let (t_min, t_max) = (l_min * 0.1, (l_max * 0.3).min(0.8));
+
+    let (s_min, s_max) = (l_min - 2.0 * t_max, l_max - 2.0 * t_min);
+    let (c_l, c_w, c_t) = (l_max - l_min, w_max - w_min, t_max - t_min);
+    // tolerances add as root sum of squares, not worst case
+    let c_s = (c_l.powi(2) + 2.0 * c_t.powi(2)).sqrt();
+    let s_max = s_max - ((s_max - s_min) - c_s) / 2.0;
+    let rms = |c: f64| (c.powi(2) + FABRICATION.powi(2) + PLACEMENT.powi(2)).sqrt();
+
+    let outer = round_up(l_min + 2.0 * toe + rms(c_l));
+    let inner = round_down(s_max - 2.0 * heel - rms(c_s)).max(0.0);
+    let width = round_up(w_min + 2.0 * side + rms(c_w));
+
+    LandPattern {
+        name: ipc7351_name(capacitor, density),
+        pad_length: length((outer - inner) / 2.0),
+        pad_width: length(width),
+        pitch: length((outer + inner) / 2.0),
+        courtyard_length: length(round_up(outer.max(l_max) + 2.0 * courtyard)),
+        courtyard_width: length(round_up(width.max(w_max) + 2.0 * courtyard)),
+    }
+}
+
+impl Capacitor {
+    /// IPC-7351 footprint name, see [ipc7351_name]
+    pub fn ipc7351_name(&self, density: Density) -> String {
+        ipc7351_name(self, density)
+    }
+
+    /// Generated footprint, see [land_pattern]
+    pub fn land_pattern(&self, density: Density) -> LandPattern {
+        land_pattern(self, density)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn ipc7351() {
+        let grm = Capacitor::from_str("GRM188R71H104KA93D").unwrap();
+        let p = grm.land_pattern(Density::N);
+        assert_eq!(p.name, "CAPC1608X90N");
+        assert_eq!((p.pad_length, p.pad_width), (Length::from_micrometres(850), Length::from_micrometres(950)));
+        assert_eq!(p.pitch, Length::from_micrometres(1600));
+        assert_eq!((p.courtyard_length, p.courtyard_width), (Length::from_micrometres(2950), Length::from_micrometres(1450)));
+        assert_eq!(grm.ipc7351_name(Density::M), "CAPC1608X90M");
+        assert_eq!(grm.ipc7351_name(Density::L), "CAPC1608X90L");
+        for (part_number, name) in &[("C1005X5R1A105K050BC", "CAPC1005X55M"), ("CL21A106KOQNNNE", "CAPC2012X135M"),
+                                     ("C1206C104K5RACTU", "CAPC3216X180M")] {
+            assert_eq!(Capacitor::from_str(part_number).unwrap().ipc7351_name(Density::M), *name);
+        }
+    }
+}
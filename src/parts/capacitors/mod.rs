@@ -1,85 +1,139 @@
 pub mod samsung;
 pub mod murata;
-use crate::parts::{EIAInchCode, Height, Dielectric, RatedVoltage, Tolerance, IECMetricCode};
+pub mod tdk;
+pub mod yageo;
+pub mod kemet;
+pub mod avx;
+pub mod taiyo_yuden;
+pub mod walsin;
+pub mod vishay;
+pub mod cross_reference;
+pub mod compatibility;
+pub mod land_pattern;
+pub mod kicad;
+use crate::parts::{EIAInchCode, Height, e_series::ESeries, parse_decimal, Dielectric, RatedVoltage, Tolerance, IECMetricCode};
 use std::str::FromStr;
 use std::fmt;
 use std::fmt::Formatter;
 
-#[derive(Debug)]
-pub enum Capacitance {
-    AttoFarads(u16),
-    PicoFarads(u64),
-    NonStandard
+/// Capacitance stored as an integer number of femtofarads, exact down to the 0.01pF resolution
+/// of the R notation codes. Constructors and sums saturate at u64::MAX femtofarads, about 18kF.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Capacitance(u64);
+
+const FEMTO_PER_PICO: u64 = 1_000;
+const FEMTO_PER_NANO: u64 = 1_000_000;
+const FEMTO_PER_MICRO: u64 = 1_000_000_000;
+const FEMTO_PER_FARAD: u64 = 1_000_000_000_000_000;
+
+impl Capacitance {
+    pub const fn from_femtofarads(ff: u64) -> Self {
+        Capacitance(ff)
+    }
+
+    pub const fn from_picofarads(pf: u64) -> Self {
+        Capacitance(pf.saturating_mul(FEMTO_PER_PICO))
+    }
+
+    pub const fn from_nanofarads(nf: u64) -> Self {
+        Capacitance(nf.saturating_mul(FEMTO_PER_NANO))
+    }
+
+    pub const fn from_microfarads(uf: u64) -> Self {
+        Capacitance(uf.saturating_mul(FEMTO_PER_MICRO))
+    }
+
+    /// Exact value
+    pub const fn femtofarads(&self) -> u64 {
+        self.0
+    }
+
+    pub fn picofarads(&self) -> f64 {
+        self.0 as f64 / FEMTO_PER_PICO as f64
+    }
+
+    pub fn nanofarads(&self) -> f64 {
+        self.0 as f64 / FEMTO_PER_NANO as f64
+    }
+
+    pub fn microfarads(&self) -> f64 {
+        self.0 as f64 / FEMTO_PER_MICRO as f64
+    }
+
+    pub fn farads(&self) -> f64 {
+        self.0 as f64 / FEMTO_PER_FARAD as f64
+    }
+
+    /// Value is in the given E-series, 4.7nF is in E6 but 4.8nF is in none
+    pub fn is_preferred(&self, series: ESeries) -> bool {
+        series.contains(self.0)
+    }
+
+    /// Nearest value of the given E-series, None below 0.1pF
+    pub fn nearest_preferred(&self, series: ESeries) -> Option<Capacitance> {
+        series.nearest(self.0).map(Capacitance)
+    }
+
+    /// All values of the given E-series in `from..=to`, ascending
+    pub fn preferred_values(series: ESeries, from: Capacitance, to: Capacitance) -> impl Iterator<Item = Capacitance> {
+        series.range(from.0, to.0).map(Capacitance)
+    }
+
+    /// Two capacitors in parallel, same as addition
+    pub fn parallel(self, other: Capacitance) -> Capacitance {
+        self + other
+    }
+
+    /// Two capacitors in series: C1 * C2 / (C1 + C2), rounded down to whole femtofarads
+    pub fn series(self, other: Capacitance) -> Capacitance {
+        let sum = self.0 as u128 + other.0 as u128;
+        if sum == 0 {
+            return Capacitance(0);
+        }
+        Capacitance((self.0 as u128 * other.0 as u128 / sum) as u64)
+    }
 }
 
-fn strip_zeros_right(x: u16) -> u16 {
-    if x % 100 == 0 {
-        x / 100
-    } else if x % 10 == 0 {
-        x / 10
-    } else {
-        x
+impl std::ops::Add for Capacitance {
+    type Output = Capacitance;
+
+    fn add(self, rhs: Capacitance) -> Capacitance {
+        Capacitance(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl std::ops::AddAssign for Capacitance {
+    fn add_assign(&mut self, rhs: Capacitance) {
+        self.0 = self.0.saturating_add(rhs.0);
+    }
+}
+
+impl std::iter::Sum for Capacitance {
+    fn sum<I: Iterator<Item = Capacitance>>(iter: I) -> Capacitance {
+        iter.fold(Capacitance(0), |a, b| a + b)
     }
 }
 
 fn format_capacitance(c: &Capacitance, infix: bool, f: &mut fmt::Formatter) -> fmt::Result {
-    let unit = if infix {
-        ("P", "N", "U")
+    let (scale, unit) = if c.0 < FEMTO_PER_NANO {
+        (FEMTO_PER_PICO, if infix { "P" } else { "pF" })
+    } else if c.0 < FEMTO_PER_MICRO {
+        (FEMTO_PER_NANO, if infix { "N" } else { "nF" })
     } else {
-        ("pF", "nF", "uF")
+        (FEMTO_PER_MICRO, if infix { "U" } else { "uF" })
     };
-    match c {
-        Capacitance::AttoFarads(a) => {
-            let a = *a;
-            if a < 1_000 {
-                if infix {
-                    write!(f, "0P{}", a / 100)
-                } else {
-                    write!(f, "0.{}pF", a / 100)
-                }
-            } else {
-                let decimal = a / 1000;
-                let fractional = a % 1000;
-                if infix {
-                    write!(f, "{}P{}", decimal, strip_zeros_right(fractional))
-                } else {
-                    write!(f, "{}.{}pF", decimal, strip_zeros_right(fractional))
-                }
-            }
-        },
-        Capacitance::PicoFarads(p) => {
-            let p = *p;
-            if p < 1_000 {
-                write!(f, "{}{}", p, unit.0)
-            } else if p >= 1_000 && p < 1_000_000 {
-                let decimal = p / 1000;
-                let fractional = (p % 1000) as u16;
-                if fractional == 0 {
-                    write!(f, "{}{}", decimal, unit.1)
-                } else {
-                    if infix {
-                        write!(f, "{}N{}", decimal, strip_zeros_right(fractional))
-                    } else {
-                        write!(f, "{}.{}nF", decimal,strip_zeros_right(fractional))
-                    }
-                }
-            } else {
-                let p = p / 1000;
-                let decimal = p / 1000;
-                let fractional = (p % 1000) as u16;
-                if fractional == 0 {
-                    write!(f, "{}{}", decimal, unit.2)
-                } else {
-                    if infix {
-                        write!(f, "{}U{}", decimal, strip_zeros_right(fractional))
-                    } else {
-                        write!(f, "{}.{}uF", decimal, strip_zeros_right(fractional))
-                    }
-                }
-            }
-
-        },
-        Capacitance::NonStandard => { write!(f, "NonSTD") },
+    let decimal = c.0 / scale;
+    let fractional = c.0 % scale;
+    if fractional == 0 {
+        return write!(f, "{}{}", decimal, unit);
+    }
+    let width = scale.ilog10() as usize;
+    let fractional = format!("{:0width$}", fractional, width = width);
+    let fractional = fractional.trim_end_matches('0');
+    if infix { // 4N7
+        write!(f, "{}{}{}", decimal, unit, fractional)
+    } else { // 4.7nF
+        write!(f, "{}.{}{}", decimal, fractional, unit)
     }
 }
 
@@ -93,21 +147,288 @@ impl std::fmt::Display for Capacitance {
     }
 }
 
-#[derive(Debug)]
+/// Decimal number with '.' or ',' separator scaled to femtofarads, fails if finer than 1fF
+fn parse_scaled(number: &str, scale: u64) -> Result<u64, Error> {
+    // 1,500pF reads as 1.5pF in Europe and 1500pF elsewhere
+    if let Some((decimal, fractional)) = number.split_once(',') {
+        if fractional.len() == 3 && !decimal.trim_start_matches('0').is_empty() {
+            return Err(Error::AmbiguousCapacitance);
+        }
+    }
+    parse_decimal(&number.replace(',', "."), scale).ok_or(Error::WrongCapacitanceCode)
+}
+
+/// Accepts values with a unit prefix (4.7uF, 470n, 0.1 µF, 1,5nF), infix notation (4u7, 4µ7, 2P2)
+/// and R notation codes (4R7, R47, 49R9). Bare numbers are rejected as ambiguous, on a schematic
+/// 470 is 470pF while the EIA code 470 is 47pF, use parse_capacitance for EIA codes. Thousands
+/// separators are ambiguous as well.
+impl FromStr for Capacitance {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if s.contains('R') && s.bytes().all(|c| c.is_ascii_digit() || c == b'R') {
+            return parse_capacitance(&s);
+        }
+        let Some((i, prefix)) = s.char_indices().find(|(_, c)| c.is_alphabetic()) else {
+            return Err(Error::AmbiguousCapacitance);
+        };
+        let scale = match prefix {
+            'p' | 'P' => FEMTO_PER_PICO,
+            'n' | 'N' => FEMTO_PER_NANO,
+            'u' | 'U' | 'µ' | 'μ' => FEMTO_PER_MICRO,
+            'F' => FEMTO_PER_FARAD,
+            // femto or farad
+            'f' => return Err(Error::AmbiguousCapacitance),
+            _ => return Err(Error::WrongCapacitanceCode)
+        };
+        let number = &s[..i];
+        let rest = &s[i + prefix.len_utf8()..];
+        let ff = match rest {
+            "" => parse_scaled(number, scale)?,
+            "F" | "f" if scale != FEMTO_PER_FARAD => parse_scaled(number, scale)?,
+            _ if number.bytes().all(|c| c.is_ascii_digit()) => parse_scaled(&format!("{}.{}", number, rest), scale)?,
+            _ => return Err(Error::WrongCapacitanceCode)
+        };
+        Ok(Capacitance(ff))
+    }
+}
+
+/// EIA capacitance code, three or four characters: significant digits and a multiplier
+/// (104 = 100nF, 4992 = 49.9nF), or R notation for small values (2R2 = 2.2pF, R47 = 0.47pF,
+/// 49R9 = 49.9pF). Multipliers 8 and 9 stand for 0.01 and 0.1 (109 = 1pF, 479 = 4.7pF).
+pub fn parse_capacitance(code: &str) -> Result<Capacitance, Error> {
+    if !(code.len() == 3 || code.len() == 4) || !code.bytes().all(|c| c.is_ascii_digit() || c == b'R') {
+        return Err(Error::WrongCapacitanceCode);
+    }
+    let digit = |c: u8| (c - b'0') as u64;
+    match code.find('R') {
+        Some(i) => {
+            let (decimal, fractional) = (&code[..i], &code[i + 1..]);
+            if fractional.is_empty() || fractional.len() > 3 || fractional.contains('R') {
+                return Err(Error::WrongCapacitanceCode);
+            }
+            let decimal = decimal.bytes().fold(0, |a, c| a * 10 + digit(c));
+            let scale = FEMTO_PER_PICO / 10u64.pow(fractional.len() as u32);
+            let fractional = fractional.bytes().fold(0, |a, c| a * 10 + digit(c));
+            Ok(Capacitance::from_femtofarads(decimal * FEMTO_PER_PICO + fractional * scale))
+        },
+        None => {
+            let (significand, exponent) = code.as_bytes().split_at(code.len() - 1);
+            let significand = significand.iter().fold(0, |a, c| a * 10 + digit(*c));
+            match exponent[0] {
+                b'8' => Ok(Capacitance::from_femtofarads(significand * 10)),
+                b'9' => Ok(Capacitance::from_femtofarads(significand * 100)),
+                e => Ok(Capacitance::from_picofarads(significand * 10u64.pow(digit(e) as u32)))
+            }
+        }
+    }
+}
+
+/// Code with `digits` significant digits and a multiplier, R notation below 10^(digits - 1) pF
+fn encode_capacitance(capacitance: &Capacitance, digits: u32) -> Result<String, Error> {
+    let ff = capacitance.femtofarads();
+    let limit = 10u64.pow(digits - 1) * FEMTO_PER_PICO;
+    if ff < limit {
+        let decimal = ff / FEMTO_PER_PICO;
+        let decimal = if decimal == 0 { String::new() } else { decimal.to_string() };
+        let fractional_digits = digits as usize - decimal.len();
+        let fractional = format!("{:03}", ff % FEMTO_PER_PICO);
+        if fractional[fractional_digits.min(3)..].bytes().any(|c| c != b'0') {
+            return Err(Error::NoCode(Attribute::Capacitance));
+        }
+        return Ok(format!("{}R{:0<width$}", decimal, &fractional[..fractional_digits.min(3)], width = fractional_digits));
+    }
+    if !ff.is_multiple_of(FEMTO_PER_PICO) {
+        return Err(Error::NoCode(Attribute::Capacitance));
+    }
+    let mut p = ff / FEMTO_PER_PICO;
+    let mut exponent = 0;
+    while p >= 10u64.pow(digits) && p.is_multiple_of(10) {
+        p /= 10;
+        exponent += 1;
+    }
+    if p >= 10u64.pow(digits) || exponent > 9 {
+        Err(Error::NoCode(Attribute::Capacitance))
+    } else {
+        Ok(format!("{}{}", p, exponent))
+    }
+}
+
+/// Inverse of parse_capacitance: two significant digits and a multiplier, R notation below 10pF
+pub fn capacitance_code(capacitance: &Capacitance) -> Result<String, Error> {
+    encode_capacitance(capacitance, 2)
+}
+
+/// Four character precision code: three significant digits and a multiplier, R notation below 100pF
+pub fn precision_capacitance_code(capacitance: &Capacitance) -> Result<String, Error> {
+    encode_capacitance(capacitance, 3)
+}
+
+/// Inverse of a code table: first code in `codes` which parses into `value`
+pub fn find_code<C: Copy, T: PartialEq>(codes: &[C], value: &T, parse: impl Fn(C) -> Result<T, Error>, attribute: Attribute) -> Result<C, Error> {
+    codes.iter()
+        .copied()
+        .find(|c| parse(*c).map(|v| v == *value).unwrap_or(false))
+        .ok_or(Error::NoCode(attribute))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Tape {
+    Paper,
+    Embossed,
+    /// Tape type is not encoded in the part number
+    Unspecified,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Reel {
+    /// Reel diameter in mm
+    pub diameter: u16,
+    pub tape: Tape,
+    /// Distance between parts on the tape in mm, when the code gives it
+    pub pitch: Option<u8>,
+    /// Standard packing quantity, pieces per reel
+    pub quantity: Option<u32>,
+}
+
+impl Reel {
+    pub fn new(diameter: u16, tape: Tape) -> Self {
+        Reel { diameter, tape, pitch: None, quantity: None }
+    }
+
+    pub fn with_pitch(diameter: u16, tape: Tape, pitch: u8) -> Self {
+        Reel { diameter, tape, pitch: Some(pitch), quantity: None }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Packaging {
+    Bulk,
+    BulkCase,
+    Reel(Reel),
+    Unspecified,
+}
+
+impl Packaging {
+    /// Without the tape pitch and per reel quantity, as found in most code tables
+    pub fn normalized(&self) -> Packaging {
+        match self {
+            Packaging::Reel(r) => Packaging::Reel(Reel::new(r.diameter, r.tape)),
+            p => *p
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Grade {
+    Commercial,
+    /// AEC-Q200 qualified
+    Automotive,
+}
+
+/// Construction features implied by the series
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct Features {
+    /// Flexible termination with a conductive resin layer, withstands board bending
+    pub soft_termination: bool,
+    /// Low loss, for RF circuits
+    pub high_q: bool,
+    /// Reversed geometry or multi terminal, for decoupling
+    pub low_esl: bool,
+}
+
+impl Grade {
+    /// Can be used wherever `other` is, automotive parts are accepted for commercial ones
+    pub fn covers(&self, other: &Grade) -> bool {
+        *other == Grade::Commercial || *self == Grade::Automotive
+    }
+}
+
+impl Features {
+    /// Has every feature of `other`
+    pub fn covers(&self, other: &Features) -> bool {
+        (!other.soft_termination || self.soft_termination)
+            && (!other.high_q || self.high_q)
+            && (!other.low_esl || self.low_esl)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Manufacturer {
+    Samsung,
+    Murata,
+    Tdk,
+    Yageo,
+    Kemet,
+    Avx,
+    TaiyoYuden,
+    Walsin,
+    Vishay,
+}
+
+impl fmt::Display for Manufacturer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use Manufacturer::*;
+        let name = match self {
+            Samsung => "Samsung Electro-Mechanics",
+            Murata => "Murata",
+            Tdk => "TDK",
+            Yageo => "Yageo",
+            Kemet => "KEMET",
+            Avx => "KYOCERA AVX",
+            TaiyoYuden => "Taiyo Yuden",
+            Walsin => "Walsin",
+            Vishay => "Vishay",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
 pub enum Series {
     Samsung(samsung::Series),
     Murata(murata::Series),
+    Tdk(tdk::Series),
+    Yageo(yageo::Series),
+    Kemet(kemet::Series),
+    Avx(avx::Series),
+    TaiyoYuden(taiyo_yuden::Series),
+    Walsin(walsin::Series),
+    Vishay(vishay::Series),
 }
 
-#[derive(Debug)]
+impl Series {
+    pub fn manufacturer(&self) -> Manufacturer {
+        match self {
+            Series::Samsung(_) => Manufacturer::Samsung,
+            Series::Murata(_) => Manufacturer::Murata,
+            Series::Tdk(_) => Manufacturer::Tdk,
+            Series::Yageo(_) => Manufacturer::Yageo,
+            Series::Kemet(_) => Manufacturer::Kemet,
+            Series::Avx(_) => Manufacturer::Avx,
+            Series::TaiyoYuden(_) => Manufacturer::TaiyoYuden,
+            Series::Walsin(_) => Manufacturer::Walsin,
+            Series::Vishay(_) => Manufacturer::Vishay,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Capacitor {
     pub series: Series,
     pub dimensions: EIAInchCode,
+    /// Nominal or maximum thickness, whichever the part number encodes
     pub max_height: Height,
     pub dielectric: Dielectric,
     pub voltage: RatedVoltage,
     pub capacitance: Capacitance,
     pub tolerance: Tolerance,
+    pub packaging: Packaging,
+    pub grade: Grade,
+    pub features: Features,
+    /// Manufacturer's design or individual specification code, identifies otherwise equal parts
+    pub specification: Option<String>,
     pub other: String,
 }
 
@@ -128,7 +449,7 @@ impl fmt::Display for Capacitor {
                 self.voltage,
                 self.dielectric,
                 self.dimensions,
-                IECMetricCode::from(self.dimensions.clone()),
+                IECMetricCode::from(self.dimensions),
                 self.max_height
             )
         }
@@ -139,12 +460,38 @@ impl fmt::Display for Capacitor {
 pub enum Error {
     UnknownSeries,
     WrongDimensionCode,
+    /// Size code with more than one reading, e.g. 0603 without Inch or Metric, or 2225 Inch to metric
+    AmbiguousSize,
     WrongHeightCode,
+    /// Length text without a known unit, e.g. 0.85 or 0.85cm
+    WrongLength,
     WrongDielectricCode,
     WrongVoltageCode,
     WrongCapacitanceCode,
+    /// Capacitance text that reads as more than one value, e.g. 1,500pF or a bare number
+    AmbiguousCapacitance,
     WrongToleranceCode,
-    InsufficientData
+    WrongProductCode,
+    WrongPackagingCode,
+    InsufficientData,
+    /// Manufacturer has no code for this attribute value, part number cannot be created
+    NoCode(Attribute)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Attribute {
+    /// Series specific fields, e.g. the NFM structure code
+    Series,
+    Grade,
+    /// Construction features, e.g. soft termination
+    Features,
+    Dimensions,
+    Height,
+    Dielectric,
+    Voltage,
+    Capacitance,
+    Tolerance,
+    Packaging,
 }
 
 macro_rules! skip_unknown {
@@ -166,7 +513,116 @@ impl FromStr for Capacitor {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         skip_unknown!(murata::parse(s));
+        skip_unknown!(samsung::parse(s));
+        skip_unknown!(tdk::parse(s));
+        skip_unknown!(yageo::parse(s));
+        skip_unknown!(kemet::parse(s));
+        skip_unknown!(avx::parse(s));
+        skip_unknown!(taiyo_yuden::parse(s));
+        skip_unknown!(walsin::parse(s));
+        skip_unknown!(vishay::parse(s));
 
         Err(Error::UnknownSeries)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_tries_every_manufacturer() {
+        for (pn, manufacturer) in &[("GRM033R61A224ME90#", Manufacturer::Murata), ("NFM18PC104R1C3D", Manufacturer::Murata),
+                                    ("CL10A106KP8NNNC", Manufacturer::Samsung), ("C1608X7R1H104K080AA", Manufacturer::Tdk),
+                                    ("CGA3E2X7R1H104K080AA", Manufacturer::Tdk), ("CC0603KRX7R9BB104", Manufacturer::Yageo),
+                                    ("C0805C104K5RACTU", Manufacturer::Kemet), ("C0603C104K5RACAUTO", Manufacturer::Kemet),
+                                    ("0603YC104KAT2A", Manufacturer::Avx), ("EMK107B7104KA-T", Manufacturer::TaiyoYuden),
+                                    ("0603B104K500CT", Manufacturer::Walsin), ("VJ0603Y104KXAAC", Manufacturer::Vishay)] {
+            let c = Capacitor::from_str(pn).unwrap();
+            assert_eq!(c.series.manufacturer(), *manufacturer, "{}", pn);
+        }
+        let c = Capacitor::from_str("GRM033R61A224ME90#").unwrap();
+        assert_eq!(c.to_string(), "CAP 220nF±20% 10V X5R 0201(0603 Metric) Height=0.3mm");
+        assert_eq!(format!("{:#}", c), "C0201_220NC10VX5R");
+        assert!(matches!(Capacitor::from_str("XYZ123"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let c1 = Capacitance::from_nanofarads(100);
+        let c2 = Capacitance::from_nanofarads(47);
+        assert_eq!(c1 + c2, Capacitance::from_nanofarads(147));
+        assert_eq!(c1.parallel(c2), c1 + c2);
+        assert_eq!(c1.series(c2).to_string(), "31.972789nF");
+        assert_eq!([c1, c2].iter().copied().sum::<Capacitance>(), c1 + c2);
+        assert!(((c1 + c2).farads() - 147e-9).abs() < 1e-18);
+        let max = Capacitance::from_femtofarads(u64::MAX);
+        assert_eq!(Capacitance::from_microfarads(u64::MAX), max);
+        assert_eq!(Capacitance::from_picofarads(u64::MAX / 10), max);
+        assert_eq!(max + c1, max);
+        let mut sum = c1;
+        sum += max;
+        assert_eq!(sum, max);
+        assert_eq!([max, max, c1].iter().copied().sum::<Capacitance>(), max);
+        assert_eq!(max.series(max), Capacitance::from_femtofarads(u64::MAX / 2));
+    }
+
+    #[test]
+    fn display() {
+        for (c, text, alternate) in &[(Capacitance::from_femtofarads(470), "0.47pF", "0P47"),
+                                      (Capacitance::from_femtofarads(2_200), "2.2pF", "2P2"),
+                                      (Capacitance::from_picofarads(4_700), "4.7nF", "4N7"),
+                                      (Capacitance::from_nanofarads(100), "100nF", "100N"),
+                                      (Capacitance::from_microfarads(10), "10uF", "10U")] {
+            assert_eq!(c.to_string(), *text);
+            assert_eq!(format!("{:#}", c), *alternate);
+        }
+    }
+
+    #[test]
+    fn capacitance_from_str() {
+        let uf4_7 = Capacitance::from_nanofarads(4_700);
+        for text in &["4.7uF", "4u7", "4µ7", "4.7 µF", "4U7", "4700nF"] {
+            assert_eq!(Capacitance::from_str(text).unwrap(), uf4_7, "{}", text);
+        }
+        assert_eq!(Capacitance::from_str("0.1 µF").unwrap(), Capacitance::from_nanofarads(100));
+        assert_eq!(Capacitance::from_str("1,5nF").unwrap(), Capacitance::from_picofarads(1_500));
+        assert_eq!(Capacitance::from_str("2P2").unwrap(), Capacitance::from_femtofarads(2_200));
+        assert_eq!(Capacitance::from_str("R47").unwrap(), Capacitance::from_femtofarads(470));
+        assert_eq!(Capacitance::from_str("4R7").unwrap(), Capacitance::from_femtofarads(4_700));
+        assert_eq!(Capacitance::from_str("49R9").unwrap(), Capacitance::from_femtofarads(49_900));
+        for text in &["104", "470", "100", "109", "4700", "47", "1,500pF", "4.7"] {
+            assert!(matches!(Capacitance::from_str(text), Err(Error::AmbiguousCapacitance)), "{}", text);
+        }
+        for text in &["1f", "1.2345fF", "RR4"] {
+            assert!(Capacitance::from_str(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn preferred_values() {
+        let c = Capacitance::from_str("4.8nF").unwrap();
+        assert!(!c.is_preferred(ESeries::E12));
+        assert_eq!(c.nearest_preferred(ESeries::E12), Some(Capacitance::from_picofarads(4_700)));
+        let values: Vec<String> = Capacitance::preferred_values(ESeries::E6, Capacitance::from_nanofarads(1), Capacitance::from_nanofarads(100))
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(values.join(" "), "1nF 1.5nF 2.2nF 3.3nF 4.7nF 6.8nF 10nF 15nF 22nF 33nF 47nF 68nF 100nF");
+    }
+
+    #[test]
+    fn codes() {
+        for (text, code, precision) in &[("0.47pF", Some("R47"), "R470"), ("4.99pF", None, "4R99"), ("49.9pF", None, "49R9"),
+                                         ("100pF", Some("101"), "1000"), ("49.9nF", None, "4992"), ("4.7uF", Some("475"), "4704")] {
+            let c = Capacitance::from_str(text).unwrap();
+            assert_eq!(capacitance_code(&c).ok().as_deref(), *code, "{}", text);
+            assert_eq!(precision_capacitance_code(&c).unwrap(), *precision);
+            assert_eq!(parse_capacitance(precision).unwrap(), c);
+            if let Some(code) = code {
+                assert_eq!(parse_capacitance(code).unwrap(), c);
+            }
+        }
+        for (code, ff) in &[("109", 1_000), ("108", 100), ("479", 4_700), ("999", 9_900), ("1009", 10_000)] {
+            assert_eq!(parse_capacitance(code).unwrap(), Capacitance::from_femtofarads(*ff), "{}", code);
+        }
+    }
+}
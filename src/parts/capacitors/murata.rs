@@ -1,27 +1,76 @@
-use crate::parts::{EIAInchCode, SizeCode, capacitors::{Capacitor, Capacitance, Error}, Height, Dielectric, RatedVoltage, Tolerance};
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, jis_voltage, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, capacitance_code, find_code}, Height, Length, Dielectric, RatedVoltage, Tolerance};
 use strum_macros::EnumString;
 use std::str::FromStr;
+use std::fmt;
+use std::fmt::Formatter;
 
-#[derive(EnumString, Debug)]
+/// Shared EIA code parser, re-exported for `murata::parse_capacitance` callers
+pub use crate::parts::capacitors::parse_capacitance;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
 pub enum Series {
+    /// Automotive, soft termination (conductive resin)
+    GCE,
+    /// Automotive, for conductive glue mounting
+    GCG,
+    /// Automotive, soft termination
+    GCJ,
+    /// Automotive, general purpose
+    GCM,
+    /// Automotive, high-Q
+    GCQ,
     GA2,
     GA3,
+    /// High-Q
     GJM,
     GMA,
     GMD,
+    /// High-Q, high power
     GQM,
     GR3,
     GR4,
+    /// Soft termination
     GRJ,
+    /// General purpose
     GRM,
+    /// AEC-Q200, for infotainment
+    GRT,
+    /// Automotive, metal terminal
     KR3,
+    /// Metal terminal
     KRM,
+    /// Low ESL, 8 terminal
     LLA,
+    /// Low ESL, reversed geometry
     LLL,
+    /// Low ESL, 10 terminal
     LLM,
-    LLR
+    /// Low ESL, controlled ESR
+    LLR,
+    /// Three terminal EMI suppression filter
+    NFM,
 }
 
+impl Series {
+    pub fn grade(&self) -> Grade {
+        use Series::*;
+        match self {
+            GCE | GCG | GCJ | GCM | GCQ | GRT | KR3 => Grade::Automotive,
+            _ => Grade::Commercial
+        }
+    }
+
+    pub fn features(&self) -> Features {
+        use Series::*;
+        Features {
+            soft_termination: matches!(self, GCE | GCJ | GRJ),
+            high_q: matches!(self, GCQ | GJM | GQM),
+            low_esl: matches!(self, LLA | LLL | LLM | LLR | NFM),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Dimensions {
     _01,
     _02,
@@ -42,6 +91,33 @@ pub enum Dimensions {
     _55
 }
 
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _01 => "01",
+            _02 => "02",
+            _0D => "0D",
+            _03 => "03",
+            _05 => "05",
+            _08 => "08",
+            _1U => "1U",
+            _15 => "15",
+            _18 => "18",
+            _21 => "21",
+            _22 => "22",
+            _31 => "31",
+            _32 => "32",
+            _42 => "42",
+            _43 => "43",
+            _52 => "52",
+            _55 => "55",
+        }
+    }
+}
+
 impl FromStr for Dimensions {
     type Err = Error;
 
@@ -70,6 +146,13 @@ impl FromStr for Dimensions {
     }
 }
 
+impl fmt::Display for Dimensions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
 impl SizeCode for Dimensions {
     type MFCode = Self;
 
@@ -123,23 +206,23 @@ impl SizeCode for Dimensions {
 
 fn parse_height_code(code: char) -> Result<Height, Error> {
     match code {
-        '1' => Ok(Height::new(0, 125)),
-        '2' => Ok(Height::new(0, 2)),
-        '3' => Ok(Height::new(0, 3)),
-        '4' => Ok(Height::new(0, 4)),
-        '5' => Ok(Height::new(0, 5)),
-        '6' => Ok(Height::new(0, 6)),
-        '7' => Ok(Height::new(0, 7)),
-        '8' => Ok(Height::new(0, 8)),
-        '9' => Ok(Height::new(0, 85)),
-        'A' => Ok(Height::new(1, 0)),
-        'B' => Ok(Height::new(1, 25)),
-        'C' => Ok(Height::new(1, 6)),
-        'D' => Ok(Height::new(2, 0)),
-        'E' => Ok(Height::new(2, 5)),
-        'M' => Ok(Height::new(1, 15)),
-        'Q' => Ok(Height::new(1, 5)),
-        'X' => Ok(Height::new(0, 0)),
+        '1' => Ok(Height::Nominal(Length::from_micrometres(125))),
+        '2' => Ok(Height::Nominal(Length::from_micrometres(200))),
+        '3' => Ok(Height::Nominal(Length::from_micrometres(300))),
+        '4' => Ok(Height::Nominal(Length::from_micrometres(400))),
+        '5' => Ok(Height::Nominal(Length::from_micrometres(500))),
+        '6' => Ok(Height::Nominal(Length::from_micrometres(600))),
+        '7' => Ok(Height::Nominal(Length::from_micrometres(700))),
+        '8' => Ok(Height::Nominal(Length::from_micrometres(800))),
+        '9' => Ok(Height::Nominal(Length::from_micrometres(850))),
+        'A' => Ok(Height::Nominal(Length::from_micrometres(1000))),
+        'B' => Ok(Height::Nominal(Length::from_micrometres(1250))),
+        'C' => Ok(Height::Nominal(Length::from_micrometres(1600))),
+        'D' => Ok(Height::Nominal(Length::from_micrometres(2000))),
+        'E' => Ok(Height::Nominal(Length::from_micrometres(2500))),
+        'M' => Ok(Height::Nominal(Length::from_micrometres(1150))),
+        'Q' => Ok(Height::Nominal(Length::from_micrometres(1500))),
+        'X' => Ok(Height::Unknown),
         _ => Err(Error::WrongHeightCode)
     }
 }
@@ -169,94 +252,111 @@ pub fn parse_dielectric(code: &str) -> Result<Dielectric, Error> {
     }
 }
 
+/// JIS voltage code, 35V and AC safety ratings have Murata specific codes
 pub fn parse_voltage(code: &str) -> Result<RatedVoltage, Error> {
     use RatedVoltage::*;
     match code {
-        "0E" => Ok(DC_2V5),
-        "0G" => Ok(DC_4V),
-        "0J" => Ok(DC_6V3),
-        "1A" => Ok(DC_10V),
-        "1C" => Ok(DC_16V),
-        "1E" => Ok(DC_25V),
-        "1H" => Ok(DC_50V),
-        "1J" => Ok(DC_63V),
-        "2A" => Ok(DC_100V),
-        "2D" => Ok(DC_200V),
-        "2E" => Ok(DC_250V),
-        "2W" => Ok(DC_450V),
-        "2H" => Ok(DC_500V),
-        "2J" => Ok(DC_630V),
-        "3A" => Ok(DC_1kV),
-        "3D" => Ok(DC_2kV),
-        "3F" => Ok(CustomDC(3150)),
         "E2" => Ok(AC_250V),
         "GB" => Ok(AC_250V),
         "GD" => Ok(AC_250V),
         "GF" => Ok(AC_250V),
         "YA" => Ok(DC_35V),
-        _ => Err(Error::WrongVoltageCode)
+        _ => jis_voltage::parse(code)
     }
 }
 
-pub fn parse_capacitance(code: &str) -> Result<Capacitance, Error> {
-    if !code.is_ascii() {
-        return Err(Error::WrongCapacitanceCode);
-    }
-    let c0 = code.bytes().nth(0).unwrap();
-    let c1 = code.bytes().nth(1).unwrap();
-    let c2 = code.bytes().nth(2).unwrap();
-    let d0 = c0 - '0' as u8;
-    let d1 = c1 - '0' as u8;
-    let d2 = c2 - '0' as u8;
-    if c0 == 'R' as u8 {
-        let c = d1 as u16 * 10 + d2 as u16;
-        Ok(Capacitance::AttoFarads(c * 10))
-    } else if c1 == 'R' as u8 {
-        let c = d0 as u16 * 10 + d2 as u16;
-        Ok(Capacitance::AttoFarads(c))
-    } else if c2 == 'R' as u8 {
-        Err(Error::WrongCapacitanceCode)
-    } else {
-        let c: u64 = d0 as u64 * 10 + d1 as u64;
-        let base: u32 = 10;
-        let z = base.pow(d2.into());
-        Ok(Capacitance::PicoFarads(c * z as u64))
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'W' | 'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
     }
 }
 
-pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
-    use Tolerance::*;
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
     match code {
-        'B' => Ok(AttoFarads(100, 100)),
-        'C' => Ok(AttoFarads(250, 250)),
-        'D' => {
-            if let Capacitance::AttoFarads(_) = capacitance {
-                Ok(AttoFarads(500, 500))
-            } else if let Capacitance::PicoFarads(p) = capacitance {
-                if *p < 10 {
-                    Ok(AttoFarads(500, 500))
-                } else {
-                    Ok(PM05)
-                }
-            } else {
-                Err(Error::WrongToleranceCode)
-            }
+        'B' => Ok(Packaging::Bulk),
+        'C' => Ok(Packaging::BulkCase),
+        'D' => Ok(Packaging::Reel(Reel::with_pitch(180, Tape::Paper, 4))),
+        'E' => Ok(Packaging::Reel(Reel::with_pitch(180, Tape::Paper, 2))),
+        'W' => Ok(Packaging::Reel(Reel::with_pitch(180, Tape::Paper, 1))),
+        'L' => Ok(Packaging::Reel(Reel::with_pitch(180, Tape::Embossed, 4))),
+        'J' => Ok(Packaging::Reel(Reel::with_pitch(330, Tape::Paper, 4))),
+        'F' => Ok(Packaging::Reel(Reel::with_pitch(330, Tape::Paper, 2))),
+        'K' => Ok(Packaging::Reel(Reel::with_pitch(330, Tape::Embossed, 4))),
+        '#' => Ok(Packaging::Unspecified),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// Catalogue packing quantity per reel, depends on the size, thickness and tape.
+pub fn standard_quantity(size: EIAInchCode, height: &Height, reel: &Reel) -> Option<u32> {
+    let large = reel.diameter >= 330;
+    match reel.tape {
+        Tape::Paper => match size {
+            EIAInchCode::_008004 | EIAInchCode::_01005 => Some(if large { 50_000 } else { 20_000 }),
+            EIAInchCode::_0201 => Some(if large { 50_000 } else { 15_000 }),
+            EIAInchCode::_0402 => Some(if large { 50_000 } else { 10_000 }),
+            EIAInchCode::_0603 | EIAInchCode::_0805 | EIAInchCode::_1206 => Some(if large { 10_000 } else { 4_000 }),
+            _ => None
         },
-        'F' => Ok(PM1),
-        'G' => Ok(PM2),
-        'J' => Ok(PM5),
-        'K' => Ok(PM10),
-        'M' => Ok(PM20),
-        'W' => Ok(AttoFarads(50, 50)),
-        _ => Err(Error::WrongToleranceCode)
+        Tape::Embossed => match height.length()?.micrometres() {
+            h if h < 1600 => Some(if large { 10_000 } else { 3_000 }),
+            h if h <= 2000 => Some(if large { 6_000 } else { 2_000 }),
+            _ => Some(if large { 4_000 } else { 1_000 }),
+        },
+        Tape::Unspecified => None
+    }
+}
+
+/// Temperature characteristic of the NFM three terminal capacitors
+fn parse_nfm_characteristic(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'B' => Ok(B),
+        'C' => Ok(CH),
+        'R' => Ok(R),
+        _ => Err(Error::WrongDielectricCode)
     }
 }
 
+/// NFM18PC104R1C3D
+/// NFM | 18 | P | C | 104 | R | 1C | 3 | D
+/// series, size, structure, category, capacitance, characteristic, voltage, electrode, packaging
+/// Tolerance is not encoded and left unknown.
+fn parse_nfm(part_number: &str) -> Result<Capacitor, Error> {
+    if part_number.len() < 15 {
+        return Err(Error::InsufficientData);
+    }
+    let dimensions = Dimensions::from_str(&part_number[3..=4])?;
+    let capacitance = parse_capacitance(&part_number[7..=9])?;
+    let dielectric = parse_nfm_characteristic(part_number.chars().nth(10).unwrap())?;
+    let voltage = parse_voltage(&part_number[11..=12])?;
+    let packaging = parse_packaging(part_number.chars().nth(14).unwrap())?;
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Murata(Series::NFM),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance: Tolerance::Unknown,
+        packaging,
+        grade: Series::NFM.grade(),
+        features: Series::NFM.features(),
+        specification: None,
+        other: format!("{}{}{}", &part_number[5..=6], &part_number[13..14], &part_number[15..])
+    })
+}
+
 pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
-    if part_number.len() < 3 {
+    if part_number.len() < 3 || !part_number.is_ascii() {
         return Err(Error::UnknownSeries);
     }
-    let series = Series::from_str(&part_number[0..3]).map(|s| crate::parts::capacitors::Series::Murata(s)).map_err(|_| Error::UnknownSeries)?;
+    let series = Series::from_str(&part_number[0..3]).map_err(|_| Error::UnknownSeries)?;
+    if series == Series::NFM {
+        return parse_nfm(part_number);
+    }
     if part_number.len() < 14 {
         return Err(Error::InsufficientData);
     }
@@ -267,19 +367,250 @@ pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
     let capacitance = parse_capacitance(&part_number[10..=12])?;
     let tolerance = parse_tolerance(&capacitance, part_number.chars().nth(13).unwrap())?;
 
-    let other = if part_number.len() >= 14 {
-        &part_number[14..]
-    } else {
-        ""
+    let dimensions = Dimensions::to_eia(dimensions);
+
+    // GRM188R71H104KA93D: A93 is the individual specification code, D is the packaging.
+    // A truncated code (GRM155R71C104KA8) is kept as it is.
+    let specification = part_number.get(14..part_number.len().min(17)).filter(|s| !s.is_empty()).map(String::from);
+    // # is a placeholder for no packaging and stays in other, so that GRM188R71H104KA93 and
+    // GRM188R71H104KA93# both re-encode as they were
+    let (mut packaging, other) = match part_number.chars().nth(17) {
+        Some('#') => (Packaging::Unspecified, &part_number[17..]),
+        Some(code) => (parse_packaging(code)?, &part_number[18..]),
+        None => (Packaging::Unspecified, "")
     };
+    if let Packaging::Reel(reel) = &mut packaging {
+        reel.quantity = standard_quantity(dimensions, &max_height, reel);
+    }
     Ok(Capacitor{
-        series,
-        dimensions: Dimensions::to_eia(dimensions),
+        series: crate::parts::capacitors::Series::Murata(series),
+        dimensions,
         max_height,
         dielectric,
         voltage,
         capacitance,
         tolerance,
+        packaging,
+        grade: series.grade(),
+        features: series.features(),
+        specification,
         other: String::from(other)
     })
-}
\ No newline at end of file
+}
+
+/// Inverse of parse_nfm, structure, category and electrode codes come from `other`.
+/// The whole series is ±20%, so only that or an unknown tolerance can be written.
+fn encode_nfm(capacitor: &Capacitor) -> Result<String, Error> {
+    if capacitor.tolerance != Tolerance::PM20 && capacitor.tolerance != Tolerance::Unknown {
+        return Err(Error::NoCode(Attribute::Tolerance));
+    }
+    let other = capacitor.other.as_str();
+    if other.len() < 3 || !other.is_ascii() {
+        return Err(Error::NoCode(Attribute::Series));
+    }
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let characteristic = find_code(&['B', 'C', 'R'], &capacitor.dielectric, parse_nfm_characteristic, Attribute::Dielectric)?;
+    Ok(format!("NFM{}{}{}{}{}{}{}{}",
+        dimensions,
+        &other[0..2],
+        capacitance_code(&capacitor.capacitance)?,
+        characteristic,
+        voltage_code(&capacitor.voltage)?,
+        &other[2..3],
+        packaging_code(&capacitor.packaging)?,
+        &other[3..]
+    ))
+}
+
+fn height_code(height: &Height) -> Result<char, Error> {
+    "123456789ABCDEMQ".chars()
+        .find(|c| parse_height_code(*c).map(|h| h == *height).unwrap_or(false))
+        .ok_or(Error::NoCode(Attribute::Height))
+}
+
+pub fn dielectric_code(dielectric: &Dielectric) -> Result<&'static str, Error> {
+    use Dielectric::*;
+    match dielectric {
+        C0G => Ok("5C"),
+        X7R => Ok("R7"),
+        X5R => Ok("R6"),
+        X6S => Ok("C8"),
+        SL => Ok("1X"),
+        CH => Ok("2C"),
+        CJ => Ok("3C"),
+        UJ => Ok("3U"),
+        CK => Ok("4C"),
+        X8G => Ok("5G"),
+        U2J => Ok("7U"),
+        B => Ok("B1"),
+        X7S => Ok("C7"),
+        X7T => Ok("D7"),
+        X6T => Ok("D8"),
+        X7U => Ok("E7"),
+        R => Ok("R1"),
+        _ => Err(Error::NoCode(Attribute::Dielectric))
+    }
+}
+
+pub fn voltage_code(voltage: &RatedVoltage) -> Result<&'static str, Error> {
+    use RatedVoltage::*;
+    match voltage {
+        DC_35V => Ok("YA"),
+        AC_250V => Ok("E2"),
+        _ => jis_voltage::encode(voltage)
+    }
+}
+
+pub fn tolerance_code(capacitance: &Capacitance, tolerance: &Tolerance) -> Result<char, Error> {
+    find_code(&['W', 'B', 'C', 'D', 'F', 'G', 'J', 'K', 'M'], tolerance, |c| parse_tolerance(capacitance, c), Attribute::Tolerance)
+}
+
+/// Exact code for Murata reels, reels of other manufacturers without a pitch get the 4mm pitch code
+pub fn packaging_code(packaging: &Packaging) -> Result<char, Error> {
+    let without_quantity = match packaging {
+        Packaging::Reel(reel) => Packaging::Reel(Reel { quantity: None, ..*reel }),
+        p => *p
+    };
+    find_code(&['B', 'C', 'D', 'E', 'W', 'L', 'J', 'F', 'K', '#'], &without_quantity, parse_packaging, Attribute::Packaging)
+        .or_else(|_| find_code(&['D', 'L', 'J', 'K'], &packaging.normalized(),
+            |c| parse_packaging(c).map(|p| p.normalized()), Attribute::Packaging))
+}
+
+/// Creates a Murata part number, inverse of parse.
+/// Series is kept for Murata parts, other parts become GRM or GCM for automotive grade.
+/// Individual specification and packaging codes are only appended when the Murata specification
+/// code is known, otherwise the result is the 14 character catalogue prefix (GRM188R71H104K).
+/// An unknown height has no code, X (height given by the individual specification) is kept for
+/// Murata parts only. The packaging follows a complete specification code only, unspecified
+/// packaging is left out unless the part had the # placeholder.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    let series = match &capacitor.series {
+        crate::parts::capacitors::Series::Murata(s) => *s,
+        _ => match capacitor.grade {
+            Grade::Automotive => Series::GCM,
+            Grade::Commercial => Series::GRM
+        }
+    };
+    if series == Series::NFM {
+        return encode_nfm(capacitor);
+    }
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let height = match (&capacitor.series, &capacitor.max_height) {
+        (crate::parts::capacitors::Series::Murata(_), Height::Unknown) => 'X',
+        (_, height) => height_code(height)?
+    };
+    let mut part_number = format!("{:?}{}{}{}{}{}{}",
+        series,
+        dimensions,
+        height,
+        dielectric_code(&capacitor.dielectric)?,
+        voltage_code(&capacitor.voltage)?,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance_code(&capacitor.capacitance, &capacitor.tolerance)?
+    );
+    // specification codes of other manufacturers mean nothing to Murata
+    if let (crate::parts::capacitors::Series::Murata(_), Some(specification)) = (&capacitor.series, &capacitor.specification) {
+        part_number.push_str(specification);
+        if specification.len() == 3 {
+            if capacitor.packaging != Packaging::Unspecified {
+                part_number.push(packaging_code(&capacitor.packaging)?);
+            }
+            part_number.push_str(&capacitor.other);
+        }
+    }
+    Ok(part_number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for pn in &["GRM033R61A224ME90#", "GCM188R71H104KA57D", "GRM188R71H104KA93D", "GRM1555C1H1R5BA01D",
+                    "GRM0335C1HR47BA01D", "GRM188R61C106KAALD", "GRM21BR61A106KE19L", "GRM188R71H104K"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+
+    #[test]
+    fn series_round_trip() {
+        use Series::*;
+        for series in &[GCE, GCG, GCJ, GCM, GCQ, GA2, GA3, GJM, GMA, GMD, GQM, GR3, GR4, GRJ, GRM, GRT,
+                        KR3, KRM, LLA, LLL, LLM, LLR] {
+            let pn = format!("{:?}188R71H104KA01D", series);
+            let c = parse(&pn).unwrap();
+            assert_eq!(c.grade, series.grade(), "{}", pn);
+            assert_eq!(encode(&c).unwrap(), pn);
+        }
+    }
+
+    #[test]
+    fn other_manufacturers() {
+        let c = Capacitor::from_str("C0402C109C5GACTU").unwrap();
+        assert_eq!(c.capacitance, Capacitance::from_picofarads(1));
+        assert!(matches!(encode(&c), Err(Error::NoCode(Attribute::Height))));
+        let c = Capacitor::from_str("CL10B104KB8NNNC").unwrap();
+        assert_eq!(encode(&c).unwrap(), "GRM188R71H104K");
+    }
+
+    #[test]
+    fn voltage_without_code() {
+        let mut c = parse("GRM188R71H104KA93D").unwrap();
+        c.voltage = RatedVoltage::DC_3kV;
+        assert!(matches!(encode(&c), Err(Error::NoCode(Attribute::Voltage))));
+    }
+
+    #[test]
+    fn truncated_specification() {
+        let c = parse("GRM155R71C104KA8").unwrap();
+        assert_eq!(c.specification.as_deref(), Some("A8"));
+        assert_eq!(c.packaging, Packaging::Unspecified);
+        assert_eq!(encode(&c).unwrap(), "GRM155R71C104KA8");
+        assert_eq!(parse("GRM155R71C104KA").unwrap().specification.as_deref(), Some("A"));
+        assert_eq!(parse("GRM155R71C104K").unwrap().specification, None);
+        for pn in &["GRM188R71H104KA93", "GRM188R71H104KA93#", "GRM033R61A224ME90#"] {
+            let c = parse(pn).unwrap();
+            assert_eq!(c.packaging, Packaging::Unspecified);
+            assert_eq!(encode(&c).unwrap(), *pn);
+        }
+    }
+
+    #[test]
+    fn specification_height() {
+        let c = parse("GRM32XR71H105KA01L").unwrap();
+        assert_eq!(c.max_height, Height::Unknown);
+        assert_eq!(encode(&c).unwrap(), "GRM32XR71H105KA01L");
+        // other manufacturers' unknown heights have no Murata code
+        let c = Capacitor::from_str("C1210C105K5RACTU").unwrap();
+        assert!(matches!(encode(&c), Err(Error::NoCode(Attribute::Height))));
+    }
+
+    #[test]
+    fn packaging_round_trip() {
+        for code in &['B', 'C', 'D', 'E', 'W', 'L', 'J', 'F', 'K', '#'] {
+            let pn = format!("GRM155R71C104KA88{}", code);
+            assert_eq!(encode(&parse(&pn).unwrap()).unwrap(), pn);
+        }
+        assert_ne!(parse_packaging('D').unwrap(), parse_packaging('E').unwrap());
+        assert_ne!(parse_packaging('J').unwrap(), parse_packaging('F').unwrap());
+        // reels of other manufacturers carry no pitch
+        assert_eq!(packaging_code(&Packaging::Reel(Reel::new(180, Tape::Paper))).unwrap(), 'D');
+        assert_eq!(packaging_code(&Packaging::Reel(Reel::new(330, Tape::Embossed))).unwrap(), 'K');
+    }
+
+    #[test]
+    fn nfm_round_trip() {
+        for pn in &["NFM18PC104R1C3D", "NFM15PC105R0J3D", "NFM21PC224R1C3D"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+        let mut c = parse("NFM18PC104R1C3D").unwrap();
+        assert_eq!(c.tolerance, Tolerance::Unknown);
+        c.tolerance = Tolerance::PM10;
+        assert!(matches!(encode(&c), Err(Error::NoCode(Attribute::Tolerance))));
+        c.tolerance = Tolerance::PM20;
+        assert_eq!(encode(&c).unwrap(), "NFM18PC104R1C3D");
+        c.other.clear();
+        assert!(matches!(encode(&c), Err(Error::NoCode(Attribute::Series))));
+    }
+}
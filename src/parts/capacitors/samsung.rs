@@ -1,11 +1,13 @@
-use crate::parts::{EIAInchCode, SizeCode};
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Height, Length, Dielectric, RatedVoltage, Tolerance};
 use strum_macros::EnumString;
+use std::str::FromStr;
 
-#[derive(EnumString, Debug)]
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
 pub enum Series {
     CL,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Dimensions {
     _02,
     _03,
@@ -19,6 +21,48 @@ pub enum Dimensions {
     _55
 }
 
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _02 => "02",
+            _03 => "03",
+            _05 => "05",
+            _10 => "10",
+            _21 => "21",
+            _31 => "31",
+            _32 => "32",
+            _42 => "42",
+            _43 => "43",
+            _55 => "55",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "02" => Ok(_02),
+            "03" => Ok(_03),
+            "05" => Ok(_05),
+            "10" => Ok(_10),
+            "21" => Ok(_21),
+            "31" => Ok(_31),
+            "32" => Ok(_32),
+            "42" => Ok(_42),
+            "43" => Ok(_43),
+            "55" => Ok(_55),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
 impl SizeCode for Dimensions {
     type MFCode = Self;
 
@@ -54,4 +98,228 @@ impl SizeCode for Dimensions {
             _ => None
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ProductCode {
+    Normal,
+    Automotive,
+}
+
+pub fn parse_dielectric(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'C' => Ok(C0G),
+        'U' => Ok(U2J),
+        'A' => Ok(X5R),
+        'B' => Ok(X7R),
+        'F' => Ok(Y5V),
+        'X' => Ok(X6S),
+        'Y' => Ok(X7S),
+        'Z' => Ok(X7T),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        'S' => Ok(DC_2V5),
+        'R' => Ok(DC_4V),
+        'Q' => Ok(DC_6V3),
+        'P' => Ok(DC_10V),
+        'O' => Ok(DC_16V),
+        'A' => Ok(DC_25V),
+        'L' => Ok(DC_35V),
+        'B' => Ok(DC_50V),
+        'C' => Ok(DC_100V),
+        'D' => Ok(DC_200V),
+        'E' => Ok(DC_250V),
+        'G' => Ok(DC_500V),
+        'H' => Ok(DC_630V),
+        'I' => Ok(DC_1kV),
+        'J' => Ok(DC_2kV),
+        'K' => Ok(DC_3kV),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'A' => Ok(Tolerance::PM0pF05),
+        'D' => Ok(Tolerance::PM0pF5),
+        'B' | 'C' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+fn parse_thickness_code(code: char) -> Result<Height, Error> {
+    match code {
+        '2' => Ok(Height::Nominal(Length::from_micrometres(200))),
+        '3' => Ok(Height::Nominal(Length::from_micrometres(300))),
+        '5' => Ok(Height::Nominal(Length::from_micrometres(500))),
+        '6' => Ok(Height::Nominal(Length::from_micrometres(600))),
+        '8' => Ok(Height::Nominal(Length::from_micrometres(800))),
+        'A' => Ok(Height::Nominal(Length::from_micrometres(650))),
+        'B' => Ok(Height::Nominal(Length::from_micrometres(750))),
+        'C' => Ok(Height::Nominal(Length::from_micrometres(850))),
+        'D' => Ok(Height::Nominal(Length::from_micrometres(1000))),
+        'F' => Ok(Height::Nominal(Length::from_micrometres(1250))),
+        'Q' => Ok(Height::Nominal(Length::from_micrometres(1250))),
+        'H' => Ok(Height::Nominal(Length::from_micrometres(1600))),
+        'I' => Ok(Height::Nominal(Length::from_micrometres(2000))),
+        'J' => Ok(Height::Nominal(Length::from_micrometres(2500))),
+        'V' => Ok(Height::Nominal(Length::from_micrometres(2500))),
+        'L' => Ok(Height::Nominal(Length::from_micrometres(3200))),
+        _ => Err(Error::WrongHeightCode)
+    }
+}
+
+pub fn parse_product_code(code: char) -> Result<ProductCode, Error> {
+    match code {
+        'N' => Ok(ProductCode::Normal),
+        'P' => Ok(ProductCode::Automotive),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        'B' => Ok(Packaging::Bulk),
+        'P' => Ok(Packaging::BulkCase),
+        'C' => Ok(Packaging::Reel(Reel::new(180, Tape::Paper))),
+        'D' => Ok(Packaging::Reel(Reel::new(330, Tape::Paper))),
+        'L' => Ok(Packaging::Reel(Reel::new(330, Tape::Paper))),
+        'O' => Ok(Packaging::Reel(Reel::new(254, Tape::Paper))),
+        'E' => Ok(Packaging::Reel(Reel::new(180, Tape::Embossed))),
+        'F' => Ok(Packaging::Reel(Reel::new(330, Tape::Embossed))),
+        'S' => Ok(Packaging::Reel(Reel::new(254, Tape::Embossed))),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// CL10A106KP8NNNC
+/// CL | 10 | A | 106 | K | P | 8 | N | N | N | C
+/// series, size, dielectric, capacitance, tolerance, voltage, thickness, design code, product code,
+/// control code, packaging
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    if part_number.len() < 2 || !part_number.is_ascii() {
+        return Err(Error::UnknownSeries);
+    }
+    let series = Series::from_str(&part_number[0..2]).map(crate::parts::capacitors::Series::Samsung).map_err(|_| Error::UnknownSeries)?;
+    if part_number.len() < 11 {
+        return Err(Error::InsufficientData);
+    }
+    let code = part_number.as_bytes();
+    let dimensions = Dimensions::from_str(&part_number[2..=3])?;
+    let dielectric = parse_dielectric(code[4] as char)?;
+    let capacitance = parse_capacitance(&part_number[5..=7])?;
+    let tolerance = parse_tolerance(&capacitance, code[8] as char)?;
+    let voltage = parse_voltage(code[9] as char)?;
+    let max_height = parse_thickness_code(code[10] as char)?;
+    let grade = match code.get(12) {
+        Some(c) => match parse_product_code(*c as char)? {
+            ProductCode::Normal => Grade::Commercial,
+            ProductCode::Automotive => Grade::Automotive,
+        },
+        None => Grade::Commercial
+    };
+    let packaging = match code.get(14) {
+        Some(c) => parse_packaging(*c as char)?,
+        None => Packaging::Unspecified
+    };
+
+    // design and control codes, followed by anything after the packaging code
+    let other = format!("{}{}{}", part_number.get(11..12).unwrap_or(""), part_number.get(13..14).unwrap_or(""),
+        part_number.get(15..).unwrap_or(""));
+    Ok(Capacitor{
+        series,
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade,
+        features: Features::default(),
+        specification: None,
+        other
+    })
+}
+
+/// Creates a Samsung part number, inverse of parse.
+/// Design and control codes are kept for Samsung parts and standard (N) otherwise, packaging defaults
+/// to 7" paper reel when the capacitor's packaging has no Samsung code.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let dielectric = find_code(&['C', 'U', 'A', 'B', 'F', 'X', 'Y', 'Z'], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let tolerance = find_code(&['A', 'B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let voltage = find_code(&['S', 'R', 'Q', 'P', 'O', 'A', 'L', 'B', 'C', 'D', 'E', 'G', 'H', 'I', 'J', 'K'],
+        &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    let thickness = find_code(&['2', '3', '5', '6', '8', 'A', 'B', 'C', 'D', 'F', 'H', 'I', 'J', 'L'],
+        &capacitor.max_height, parse_thickness_code, Attribute::Height)?;
+    let product = match capacitor.grade {
+        Grade::Commercial => 'N',
+        Grade::Automotive => 'P'
+    };
+    let packaging = find_code(&['C', 'D', 'E', 'F', 'O', 'S', 'B', 'P'], &capacitor.packaging.normalized(), parse_packaging, Attribute::Packaging)
+        .unwrap_or('C');
+    let (design, control, suffix) = match capacitor.series {
+        crate::parts::capacitors::Series::Samsung(_) if capacitor.other.len() >= 2 =>
+            (&capacitor.other[0..1], &capacitor.other[1..2], &capacitor.other[2..]),
+        _ => ("N", "N", "")
+    };
+    Ok(format!("CL{}{}{}{}{}{}{}{}{}{}{}",
+        dimensions.code(),
+        dielectric,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        voltage,
+        thickness,
+        design,
+        product,
+        control,
+        packaging,
+        suffix
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn undecoded_characters() {
+        let c = parse("CL10B104KB8NNNCX").unwrap();
+        assert_eq!(c.other, "NNX");
+        assert_eq!(c.packaging, Packaging::Reel(Reel::new(180, Tape::Paper)));
+        assert_eq!(parse("CL10B104KB8").unwrap().other, "");
+        assert_eq!(parse("CL10B104KB8NP").unwrap().grade, Grade::Automotive);
+        assert!(matches!(parse("CL10B104KB8NNNQ"), Err(Error::WrongPackagingCode)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["CL10A106KP8NNNC", "CL10B104KB8NNNCX", "CL05A104KA5NNNC", "CL31B105KBHNNNE"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+
+    #[test]
+    fn non_ascii_is_unknown() {
+        for pn in &["Gé", "Cé", "CLé", "CL10A106KP8NNNé", "é", "µF"] {
+            assert!(matches!(parse(pn), Err(Error::UnknownSeries)), "{}", pn);
+            assert!(Capacitor::from_str(pn).is_err(), "{}", pn);
+        }
+        // every vendor parser sees the input, none may slice through a multi-byte character
+        for pn in &["CL10A106KP8NNNC", "GRM188R71H104KA93D", "CGA3E2X7R1H104K080AA", "C0603C104K5RACTU",
+                    "0603YC104KAT2A", "EMK107B7104KA-T", "0603B104K500CT", "VJ0603Y104KXAAC", "CC0603KRX7R9BB104"] {
+            for i in 0..=pn.len() {
+                let pn = format!("{}é{}", &pn[..i], &pn[i..]);
+                assert!(Capacitor::from_str(&pn).is_err(), "{}", pn);
+            }
+        }
+    }
+}
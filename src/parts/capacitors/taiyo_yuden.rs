@@ -0,0 +1,263 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Dielectric, Height, Length, RatedVoltage, Tolerance};
+use strum_macros::EnumString;
+use std::str::FromStr;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// Multilayer ceramic capacitor
+    MK,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _042,
+    _063,
+    _105,
+    _107,
+    _212,
+    _316,
+    _325,
+    _432,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _042 => "042",
+            _063 => "063",
+            _105 => "105",
+            _107 => "107",
+            _212 => "212",
+            _316 => "316",
+            _325 => "325",
+            _432 => "432",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "042" => Ok(_042),
+            "063" => Ok(_063),
+            "105" => Ok(_105),
+            "107" => Ok(_107),
+            "212" => Ok(_212),
+            "316" => Ok(_316),
+            "325" => Ok(_325),
+            "432" => Ok(_432),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _042 => { EIAInchCode::_01005 },
+            _063 => { EIAInchCode::_0201 },
+            _105 => { EIAInchCode::_0402 },
+            _107 => { EIAInchCode::_0603 },
+            _212 => { EIAInchCode::_0805 },
+            _316 => { EIAInchCode::_1206 },
+            _325 => { EIAInchCode::_1210 },
+            _432 => { EIAInchCode::_1812 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_01005 => { Some(_042) },
+            EIAInchCode::_0201 => { Some(_063) },
+            EIAInchCode::_0402 => { Some(_105) },
+            EIAInchCode::_0603 => { Some(_107) },
+            EIAInchCode::_0805 => { Some(_212) },
+            EIAInchCode::_1206 => { Some(_316) },
+            EIAInchCode::_1210 => { Some(_325) },
+            EIAInchCode::_1812 => { Some(_432) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        'A' => Ok(DC_4V),
+        'J' => Ok(DC_6V3),
+        'L' => Ok(DC_10V),
+        'E' => Ok(DC_16V),
+        'T' => Ok(DC_25V),
+        'G' => Ok(DC_35V),
+        'U' => Ok(DC_50V),
+        'H' => Ok(DC_100V),
+        'Q' => Ok(DC_250V),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+/// Combined JIS/EIA temperature characteristic code: BJ is B (JIS) and X5R (EIA)
+pub fn parse_dielectric(code: &str) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        "BJ" => Ok(X5R),
+        "B7" => Ok(X7R),
+        "C6" => Ok(X6S),
+        "C7" => Ok(X7S),
+        "CG" => Ok(C0G),
+        "CH" => Ok(CH),
+        "SL" => Ok(SL),
+        "UJ" => Ok(UJ),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+fn parse_thickness_code(code: char) -> Result<Height, Error> {
+    match code {
+        'H' => Ok(Height::Nominal(Length::from_micrometres(200))),
+        'P' => Ok(Height::Nominal(Length::from_micrometres(300))),
+        'V' => Ok(Height::Nominal(Length::from_micrometres(500))),
+        'C' => Ok(Height::Nominal(Length::from_micrometres(700))),
+        'A' => Ok(Height::Nominal(Length::from_micrometres(800))),
+        'D' => Ok(Height::Nominal(Length::from_micrometres(850))),
+        'F' => Ok(Height::Nominal(Length::from_micrometres(1150))),
+        'G' => Ok(Height::Nominal(Length::from_micrometres(1250))),
+        'L' => Ok(Height::Nominal(Length::from_micrometres(1600))),
+        'N' => Ok(Height::Nominal(Length::from_micrometres(1900))),
+        'Y' => Ok(Height::Nominal(Length::from_micrometres(2000))),
+        'M' => Ok(Height::Nominal(Length::from_micrometres(2500))),
+        _ => Err(Error::WrongHeightCode)
+    }
+}
+
+/// Dash separated suffix
+pub fn parse_packaging(code: &str) -> Result<Packaging, Error> {
+    match code {
+        "" => Ok(Packaging::Unspecified),
+        "T" => Ok(Packaging::Reel(Reel::new(180, Tape::Unspecified))),
+        "B" => Ok(Packaging::Bulk),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// EMK107B7104KA-T
+/// E | MK | 107 | B7 | 104 | K | A | -T
+/// JMK316AB7106KL-T
+/// J | MK | 316 | A | B7 | 106 | K | L | -T
+/// voltage, series, size, (special code), dielectric, capacitance, tolerance, thickness, packaging
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    if !part_number.is_ascii() || part_number.len() < 3 {
+        return Err(Error::UnknownSeries);
+    }
+    let series = Series::from_str(&part_number[1..3]).map_err(|_| Error::UnknownSeries)?;
+    let (code, suffix) = match part_number.find('-') {
+        Some(i) => (&part_number[..i], &part_number[i + 1..]),
+        None => (part_number, "")
+    };
+    if code.len() < 13 {
+        return Err(Error::InsufficientData);
+    }
+    let voltage = parse_voltage(code.as_bytes()[0] as char)?;
+    let dimensions = Dimensions::from_str(&code[3..6])?;
+    let (special, rest) = match parse_dielectric(&code[6..8]) {
+        Ok(_) => ("", &code[6..]),
+        Err(_) => (&code[6..7], &code[7..])
+    };
+    if rest.len() < 7 {
+        return Err(Error::InsufficientData);
+    }
+    let dielectric = parse_dielectric(&rest[0..2])?;
+    let capacitance = parse_capacitance(&rest[2..5])?;
+    let tolerance = parse_tolerance(&capacitance, rest.as_bytes()[5] as char)?;
+    let max_height = parse_thickness_code(rest.as_bytes()[6] as char)?;
+    let packaging = parse_packaging(suffix)?;
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::TaiyoYuden(series),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade: Grade::Commercial,
+        features: Features::default(),
+        specification: if special.is_empty() { None } else { Some(String::from(special)) },
+        other: String::from(&rest[7..])
+    })
+}
+
+/// Creates a Taiyo Yuden part number, inverse of parse. The special code and trailing characters
+/// are kept for Taiyo Yuden parts. Packaging is tape and reel (-T) unless bulk or unspecified.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    if capacitor.grade == Grade::Automotive {
+        return Err(Error::NoCode(Attribute::Grade));
+    }
+    let voltage = find_code(&['A', 'J', 'L', 'E', 'T', 'G', 'U', 'H', 'Q'], &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let dielectric = find_code(&["BJ", "B7", "C6", "C7", "CG", "CH", "SL", "UJ"], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let thickness = find_code(&['H', 'P', 'V', 'C', 'A', 'D', 'F', 'G', 'L', 'N', 'Y', 'M'],
+        &capacitor.max_height, parse_thickness_code, Attribute::Height)?;
+    let (special, other) = match capacitor.series {
+        crate::parts::capacitors::Series::TaiyoYuden(_) =>
+            (capacitor.specification.as_deref().unwrap_or(""), capacitor.other.as_str()),
+        _ => ("", "")
+    };
+    let packaging = match capacitor.packaging {
+        Packaging::Bulk => "-B",
+        Packaging::Unspecified => "",
+        _ => "-T"
+    };
+    Ok(format!("{}MK{}{}{}{}{}{}{}{}",
+        voltage,
+        dimensions.code(),
+        special,
+        dielectric,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        thickness,
+        other,
+        packaging
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn special_code() {
+        let c = parse("JMK316AB7106KL-T").unwrap();
+        assert_eq!(c.specification.as_deref(), Some("A"));
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(parse("EMK107B7104KA-T").unwrap().specification, None);
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["EMK107B7104KA-T", "JMK316AB7106KL-T", "JMK212BJ106KG-T", "LMK105BJ104KV-B", "TMK063CG100DP"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,389 @@
+use crate::parts::{EIAInchCode, eia_tolerance, IECMetricCode, SizeCode, jis_voltage, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Height, Length, Dielectric, RatedVoltage, Tolerance};
+use strum_macros::EnumString;
+use std::str::FromStr;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// General purpose
+    C,
+    /// Automotive grade
+    CGA,
+}
+
+/// Metric size code used by the C series: C1608 is 1608 Metric (0603 Inch)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0402,
+    _0603,
+    _1005,
+    _1608,
+    _2012,
+    _3216,
+    _3225,
+    _4520,
+    _4532,
+    _5750,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0402 => "0402",
+            _0603 => "0603",
+            _1005 => "1005",
+            _1608 => "1608",
+            _2012 => "2012",
+            _3216 => "3216",
+            _3225 => "3225",
+            _4520 => "4520",
+            _4532 => "4532",
+            _5750 => "5750",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "1005" => Ok(_1005),
+            "1608" => Ok(_1608),
+            "2012" => Ok(_2012),
+            "3216" => Ok(_3216),
+            "3225" => Ok(_3225),
+            "4520" => Ok(_4520),
+            "4532" => Ok(_4532),
+            "5750" => Ok(_5750),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        let metric = match code {
+            _0402 => { IECMetricCode::_0402 },
+            _0603 => { IECMetricCode::_0603 },
+            _1005 => { IECMetricCode::_1005 },
+            _1608 => { IECMetricCode::_1608 },
+            _2012 => { IECMetricCode::_2012 },
+            _3216 => { IECMetricCode::_3216 },
+            _3225 => { IECMetricCode::_3225 },
+            _4520 => { IECMetricCode::_4520 },
+            _4532 => { IECMetricCode::_4532 },
+            _5750 => { IECMetricCode::_5750 },
+        };
+        EIAInchCode::from(metric)
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match IECMetricCode::from(size) {
+            IECMetricCode::_0402 => { Some(_0402) },
+            IECMetricCode::_0603 => { Some(_0603) },
+            IECMetricCode::_1005 => { Some(_1005) },
+            IECMetricCode::_1608 => { Some(_1608) },
+            IECMetricCode::_2012 => { Some(_2012) },
+            IECMetricCode::_3216 => { Some(_3216) },
+            IECMetricCode::_3225 => { Some(_3225) },
+            IECMetricCode::_4520 => { Some(_4520) },
+            IECMetricCode::_4532 => { Some(_4532) },
+            IECMetricCode::_5750 => { Some(_5750) },
+            _ => None
+        }
+    }
+}
+
+/// Single digit size code used by the CGA series: CGA3 is 1608 Metric (0603 Inch)
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AutomotiveDimensions {
+    _1,
+    _2,
+    _3,
+    _4,
+    _5,
+    _6,
+    _8,
+    _9,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl AutomotiveDimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use AutomotiveDimensions::*;
+        match self {
+            _1 => "1",
+            _2 => "2",
+            _3 => "3",
+            _4 => "4",
+            _5 => "5",
+            _6 => "6",
+            _8 => "8",
+            _9 => "9",
+        }
+    }
+}
+
+impl FromStr for AutomotiveDimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use AutomotiveDimensions::*;
+        match s {
+            "1" => Ok(_1),
+            "2" => Ok(_2),
+            "3" => Ok(_3),
+            "4" => Ok(_4),
+            "5" => Ok(_5),
+            "6" => Ok(_6),
+            "8" => Ok(_8),
+            "9" => Ok(_9),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for AutomotiveDimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use AutomotiveDimensions::*;
+        match code {
+            _1 => { EIAInchCode::_0201 },
+            _2 => { EIAInchCode::_0402 },
+            _3 => { EIAInchCode::_0603 },
+            _4 => { EIAInchCode::_0805 },
+            _5 => { EIAInchCode::_1206 },
+            _6 => { EIAInchCode::_1210 },
+            _8 => { EIAInchCode::_1812 },
+            _9 => { EIAInchCode::_2220 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use AutomotiveDimensions::*;
+        match size {
+            EIAInchCode::_0201 => { Some(_1) },
+            EIAInchCode::_0402 => { Some(_2) },
+            EIAInchCode::_0603 => { Some(_3) },
+            EIAInchCode::_0805 => { Some(_4) },
+            EIAInchCode::_1206 => { Some(_5) },
+            EIAInchCode::_1210 => { Some(_6) },
+            EIAInchCode::_1812 => { Some(_8) },
+            EIAInchCode::_2220 => { Some(_9) },
+            _ => None
+        }
+    }
+}
+
+/// Returns dielectric and code length
+pub fn parse_dielectric(code: &str) -> Result<(Dielectric, usize), Error> {
+    use Dielectric::*;
+    if code.len() >= 3 {
+        let d = match &code[0..3] {
+            "C0G" => Some(C0G),
+            "NP0" => Some(C0G),
+            "X5R" => Some(X5R),
+            "X6S" => Some(X6S),
+            "X6T" => Some(X6T),
+            "X7R" => Some(X7R),
+            "X7S" => Some(X7S),
+            "X7T" => Some(X7T),
+            "X8R" => Some(X8R),
+            "X8G" => Some(X8G),
+            "U2J" => Some(U2J),
+            "Y5V" => Some(Y5V),
+            _ => None
+        };
+        if let Some(d) = d {
+            return Ok((d, 3));
+        }
+    }
+    if code.len() >= 2 {
+        match &code[0..2] {
+            "CH" => return Ok((CH, 2)),
+            "JB" => return Ok((B, 2)),
+            _ => {}
+        }
+    }
+    Err(Error::WrongDielectricCode)
+}
+
+/// JIS voltage code, see [jis_voltage]
+pub fn parse_voltage(code: &str) -> Result<RatedVoltage, Error> {
+    jis_voltage::parse(code)
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+/// Nominal thickness in 0.01mm: 080 = 0.8mm, 125 = 1.25mm
+fn parse_thickness(code: &str) -> Result<Height, Error> {
+    // u16::from_str takes a leading sign as well
+    if code.len() != 3 || !code.bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Error::WrongHeightCode);
+    }
+    let t = u16::from_str(code).map_err(|_| Error::WrongHeightCode)?;
+    if t == 0 {
+        return Err(Error::WrongHeightCode);
+    }
+    Ok(Height::Nominal(Length::from_micrometres(t as u32 * 10)))
+}
+
+/// Reel only, the code gives the reel diameter and the pitch. 2mm pitch is paper tape, 4mm pitch
+/// tape is paper or embossed depending on the thickness, which the code does not say.
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        'A' => Ok(Packaging::Reel(Reel::with_pitch(178, Tape::Unspecified, 4))),
+        'B' => Ok(Packaging::Reel(Reel::with_pitch(178, Tape::Paper, 2))),
+        'K' => Ok(Packaging::Reel(Reel::with_pitch(330, Tape::Unspecified, 4))),
+        'L' => Ok(Packaging::Reel(Reel::with_pitch(330, Tape::Paper, 2))),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// C1608X7R1H104K080AA
+/// C | 1608 | X7R | 1H | 104 | K | 080 | A | A
+/// CGA3E2X7R1H104K080AA
+/// CGA | 3 | E2 | X7R | 1H | 104 | K | 080 | A | A
+/// series, size, (thickness class and internal code), dielectric, voltage, capacitance, tolerance,
+/// thickness, packaging, special code
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    if !part_number.is_ascii() {
+        return Err(Error::UnknownSeries);
+    }
+    let (series, dimensions, rest) = if part_number.starts_with("CGA") {
+        if part_number.len() < 6 {
+            return Err(Error::InsufficientData);
+        }
+        let dimensions = AutomotiveDimensions::from_str(&part_number[3..4])?;
+        (Series::CGA, AutomotiveDimensions::to_eia(dimensions), &part_number[6..])
+    } else if part_number.starts_with('C') && part_number.len() > 5 && part_number[1..5].bytes().all(|c| c.is_ascii_digit()) {
+        let dimensions = Dimensions::from_str(&part_number[1..5]).map_err(|_| Error::UnknownSeries)?;
+        (Series::C, Dimensions::to_eia(dimensions), &part_number[5..])
+    } else {
+        return Err(Error::UnknownSeries);
+    };
+    let (dielectric, len) = match (parse_dielectric(rest), &series) {
+        (Ok(d), _) => d,
+        // C0603C104K5RAC is a KEMET 0603 Inch part, not a TDK 0603 Metric one
+        (Err(_), Series::C) => return Err(Error::UnknownSeries),
+        (Err(e), _) => return Err(e)
+    };
+    let rest = &rest[len..];
+    if rest.len() < 11 {
+        return Err(Error::InsufficientData);
+    }
+    let voltage = parse_voltage(&rest[0..2])?;
+    let capacitance = parse_capacitance(&rest[2..5])?;
+    let tolerance = parse_tolerance(&capacitance, rest.as_bytes()[5] as char)?;
+    let max_height = parse_thickness(&rest[6..9])?;
+    let packaging = parse_packaging(rest.as_bytes()[9] as char)?;
+
+    let grade = match series {
+        Series::CGA => Grade::Automotive,
+        Series::C => Grade::Commercial
+    };
+    let other = match series {
+        Series::CGA => format!("{}{}", &part_number[4..6], &rest[10..]),
+        Series::C => String::from(&rest[10..])
+    };
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Tdk(series),
+        dimensions,
+        max_height,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade,
+        features: Features::default(),
+        specification: None,
+        other
+    })
+}
+
+/// Creates a TDK C series part number, inverse of parse.
+/// CGA needs the thickness class and internal code which are not derivable from the capacitor,
+/// automotive parts are reported as having no code.
+/// Packaging and special code are kept for TDK parts, others get a 178mm reel with the pitch TDK
+/// uses for the size and the standard special code A.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    if capacitor.grade == Grade::Automotive {
+        return Err(Error::NoCode(Attribute::Grade));
+    }
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let dielectric = find_code(&["C0G", "X5R", "X6S", "X6T", "X7R", "X7S", "X7T", "X8R", "X8G", "U2J", "Y5V", "CH", "JB"],
+        &capacitor.dielectric, |c| parse_dielectric(c).map(|(d, _)| d), Attribute::Dielectric)?;
+    let voltage = jis_voltage::encode(&capacitor.voltage)?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let thickness = match capacitor.max_height {
+        Height::Nominal(t) if t.micrometres() % 10 == 0 && t.micrometres() <= 9990 => t.micrometres() / 10,
+        _ => return Err(Error::NoCode(Attribute::Height))
+    };
+    let small = matches!(dimensions, Dimensions::_0402 | Dimensions::_0603 | Dimensions::_1005);
+    let packaging = find_code(&['A', 'B', 'K', 'L'], &capacitor.packaging, parse_packaging, Attribute::Packaging)
+        .unwrap_or(if small { 'B' } else { 'A' });
+    let special = match capacitor.series {
+        crate::parts::capacitors::Series::Tdk(Series::C) if !capacitor.other.is_empty() => capacitor.other.as_str(),
+        _ => "A"
+    };
+    Ok(format!("C{}{}{}{}{}{:03}{}{}",
+        dimensions.code(),
+        dielectric,
+        voltage,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        thickness,
+        packaging,
+        special
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packaging_codes() {
+        assert_eq!(parse_packaging('A').unwrap(), Packaging::Reel(Reel::with_pitch(178, Tape::Unspecified, 4)));
+        assert_eq!(parse_packaging('B').unwrap(), Packaging::Reel(Reel::with_pitch(178, Tape::Paper, 2)));
+        assert_ne!(parse_packaging('A').unwrap(), parse_packaging('B').unwrap());
+        assert_ne!(parse_packaging('K').unwrap(), parse_packaging('L').unwrap());
+        assert!(parse_packaging('X').is_err());
+    }
+
+    #[test]
+    fn thickness_digits_only() {
+        for pn in &["C1608X7R1H104K+80AA", "C1608X7R1H104K-80AA", "C1608X7R1H104K 80AA", "C1608X7R1H104K000AA"] {
+            assert!(matches!(parse(pn), Err(Error::WrongHeightCode)), "{}", pn);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["C1608X7R1H104K080AA", "C1005X5R1A105K050BC", "C3216X7R1H105K160AB", "C2012X7R1E105K125KA",
+                    "C0603C0G1H100D030BA"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Dielectric, Height, RatedVoltage, Tolerance};
+use strum_macros::EnumString;
+use std::str::FromStr;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// Vitramon commercial MLCC
+    VJ,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0402,
+    _0603,
+    _0805,
+    _1206,
+    _1210,
+    _1808,
+    _1812,
+    _1825,
+    _2220,
+    _2225,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0402 => "0402",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1808 => "1808",
+            _1812 => "1812",
+            _1825 => "1825",
+            _2220 => "2220",
+            _2225 => "2225",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "0805" => Ok(_0805),
+            "1206" => Ok(_1206),
+            "1210" => Ok(_1210),
+            "1808" => Ok(_1808),
+            "1812" => Ok(_1812),
+            "1825" => Ok(_1825),
+            "2220" => Ok(_2220),
+            "2225" => Ok(_2225),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _0402 => { EIAInchCode::_0402 },
+            _0603 => { EIAInchCode::_0603 },
+            _0805 => { EIAInchCode::_0805 },
+            _1206 => { EIAInchCode::_1206 },
+            _1210 => { EIAInchCode::_1210 },
+            _1808 => { EIAInchCode::_1808 },
+            _1812 => { EIAInchCode::_1812 },
+            _1825 => { EIAInchCode::_1825 },
+            _2220 => { EIAInchCode::_2220 },
+            _2225 => { EIAInchCode::_2225 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_0402 => { Some(_0402) },
+            EIAInchCode::_0603 => { Some(_0603) },
+            EIAInchCode::_0805 => { Some(_0805) },
+            EIAInchCode::_1206 => { Some(_1206) },
+            EIAInchCode::_1210 => { Some(_1210) },
+            EIAInchCode::_1808 => { Some(_1808) },
+            EIAInchCode::_1812 => { Some(_1812) },
+            EIAInchCode::_1825 => { Some(_1825) },
+            EIAInchCode::_2220 => { Some(_2220) },
+            EIAInchCode::_2225 => { Some(_2225) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_dielectric(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'A' => Ok(C0G),
+        'Y' => Ok(X7R),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        'Q' => Ok(DC_10V),
+        'J' => Ok(DC_16V),
+        'X' => Ok(DC_25V),
+        'A' => Ok(DC_50V),
+        'B' => Ok(DC_100V),
+        'C' => Ok(DC_200V),
+        'E' => Ok(DC_500V),
+        'L' => Ok(DC_630V),
+        'G' => Ok(DC_1kV),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        'T' => Ok(Packaging::Reel(Reel::new(180, Tape::Embossed))),
+        'C' => Ok(Packaging::Reel(Reel::new(180, Tape::Paper))),
+        'R' => Ok(Packaging::Reel(Reel::new(286, Tape::Embossed))),
+        'P' => Ok(Packaging::Reel(Reel::new(286, Tape::Paper))),
+        'B' => Ok(Packaging::Bulk),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// VJ0603Y104KXAAC
+/// VJ | 0603 | Y | 104 | K | X | A | A | C
+/// series, size, dielectric, capacitance, tolerance, termination, voltage, marking, packaging
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    if !part_number.is_ascii() || part_number.len() < 2 {
+        return Err(Error::UnknownSeries);
+    }
+    let series = Series::from_str(&part_number[0..2]).map_err(|_| Error::UnknownSeries)?;
+    if part_number.len() < 15 {
+        return Err(Error::InsufficientData);
+    }
+    let code = part_number.as_bytes();
+    let dimensions = Dimensions::from_str(&part_number[2..6])?;
+    let dielectric = parse_dielectric(code[6] as char)?;
+    let capacitance = parse_capacitance(&part_number[7..10])?;
+    let tolerance = parse_tolerance(&capacitance, code[10] as char)?;
+    let voltage = parse_voltage(code[12] as char)?;
+    let packaging = parse_packaging(code[14] as char)?;
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Vishay(series),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade: Grade::Commercial,
+        features: Features::default(),
+        specification: None,
+        other: format!("{}{}{}", &part_number[11..12], &part_number[13..14], &part_number[15..])
+    })
+}
+
+/// Creates a Vishay VJ part number, inverse of parse.
+/// Termination is Ni barrier 100% tin (X), unmarked (A), 7" paper reel (C).
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    if capacitor.grade == Grade::Automotive {
+        return Err(Error::NoCode(Attribute::Grade));
+    }
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let dielectric = find_code(&['A', 'Y'], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let voltage = find_code(&['Q', 'J', 'X', 'A', 'B', 'C', 'E', 'L', 'G'], &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    Ok(format!("VJ{}{}{}{}X{}AC",
+        dimensions.code(),
+        dielectric,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        voltage
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let c = parse("VJ0603Y104KXAAC").unwrap();
+        assert_eq!(c.dimensions, EIAInchCode::_0603);
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(c.capacitance, Capacitance::from_nanofarads(100));
+        assert_eq!(c.tolerance, Tolerance::PM10);
+        assert_eq!(c.voltage, RatedVoltage::DC_50V);
+        assert_eq!(c.packaging, Packaging::Reel(Reel::new(180, Tape::Paper)));
+        assert_eq!(c.other, "XA");
+        assert_eq!(parse("VJ0402A1R0CXAAT").unwrap().dielectric, Dielectric::C0G);
+    }
+
+    #[test]
+    fn invalid_codes() {
+        assert!(matches!(parse("VJ0603Y104KXAA"), Err(Error::InsufficientData)));
+        assert!(matches!(parse("VJ0603Q104KXAAC"), Err(Error::WrongDielectricCode)));
+        assert!(matches!(parse("VJ0603Y104KXZAC"), Err(Error::WrongVoltageCode)));
+        assert!(matches!(parse("VJ0603Y104KXAAZ"), Err(Error::WrongPackagingCode)));
+        assert!(matches!(parse("VK0603Y104KXAAC"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["VJ0603Y104KXAAC", "VJ0402A1R0CXAAC", "VJ0805Y105KXJAC", "VJ1206Y224KXBAC"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Dielectric, Height, RatedVoltage, Tolerance};
+use std::str::FromStr;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// General purpose, no series prefix in the part number
+    General,
+}
+
+/// Walsin part numbers start with the EIA inch code
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0201,
+    _0402,
+    _0603,
+    _0805,
+    _1206,
+    _1210,
+    _1808,
+    _1812,
+    _2220,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0201 => "0201",
+            _0402 => "0402",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1808 => "1808",
+            _1812 => "1812",
+            _2220 => "2220",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0201" => Ok(_0201),
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "0805" => Ok(_0805),
+            "1206" => Ok(_1206),
+            "1210" => Ok(_1210),
+            "1808" => Ok(_1808),
+            "1812" => Ok(_1812),
+            "2220" => Ok(_2220),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _0201 => { EIAInchCode::_0201 },
+            _0402 => { EIAInchCode::_0402 },
+            _0603 => { EIAInchCode::_0603 },
+            _0805 => { EIAInchCode::_0805 },
+            _1206 => { EIAInchCode::_1206 },
+            _1210 => { EIAInchCode::_1210 },
+            _1808 => { EIAInchCode::_1808 },
+            _1812 => { EIAInchCode::_1812 },
+            _2220 => { EIAInchCode::_2220 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_0201 => { Some(_0201) },
+            EIAInchCode::_0402 => { Some(_0402) },
+            EIAInchCode::_0603 => { Some(_0603) },
+            EIAInchCode::_0805 => { Some(_0805) },
+            EIAInchCode::_1206 => { Some(_1206) },
+            EIAInchCode::_1210 => { Some(_1210) },
+            EIAInchCode::_1808 => { Some(_1808) },
+            EIAInchCode::_1812 => { Some(_1812) },
+            EIAInchCode::_2220 => { Some(_2220) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_dielectric(code: char) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        'N' => Ok(C0G),
+        'B' => Ok(X7R),
+        'X' => Ok(X5R),
+        'F' => Ok(Y5V),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+/// Voltage in the same notation as capacitance: 500 = 50V, 101 = 100V, 6R3 = 6.3V
+pub fn parse_voltage(code: &str) -> Result<RatedVoltage, Error> {
+    let b = code.as_bytes();
+    if code.len() != 3 {
+        return Err(Error::WrongVoltageCode);
+    }
+    let digit = |c: u8| if c.is_ascii_digit() { Ok((c - b'0') as u32) } else { Err(Error::WrongVoltageCode) };
+    let decivolts = if b[1] == b'R' {
+        digit(b[0])? * 10 + digit(b[2])?
+    } else {
+        let significant = digit(b[0])? * 10 + digit(b[1])?;
+        10u32.checked_pow(digit(b[2])? + 1)
+            .and_then(|m| m.checked_mul(significant))
+            .ok_or(Error::WrongVoltageCode)?
+    };
+    Ok(RatedVoltage::from_millivolts(decivolts as u64 * 100, true))
+}
+
+/// Termination: C = Cu/Ni/Sn, the only one in the general purpose series
+pub fn parse_termination(code: char) -> Result<(), Error> {
+    match code {
+        'C' => Ok(()),
+        _ => Err(Error::WrongProductCode)
+    }
+}
+
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        'T' => Ok(Packaging::Reel(Reel::new(180, Tape::Unspecified))),
+        'B' => Ok(Packaging::Bulk),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+/// 0603B104K500CT
+/// 0603 | B | 104 | K | 500 | C | T
+/// size, dielectric, capacitance, tolerance, voltage, termination, packaging
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    // Dielectric letter followed by the capacitance code, AVX has two letters there
+    if !part_number.is_ascii() || part_number.len() < 6
+        || !part_number[0..4].bytes().all(|c| c.is_ascii_digit())
+        || !part_number.as_bytes()[4].is_ascii_alphabetic()
+        || !(part_number.as_bytes()[5].is_ascii_digit() || part_number.as_bytes()[5] == b'R') {
+        return Err(Error::UnknownSeries);
+    }
+    if part_number.len() < 14 {
+        return Err(Error::InsufficientData);
+    }
+    let code = part_number.as_bytes();
+    let dimensions = Dimensions::from_str(&part_number[0..4])?;
+    let dielectric = parse_dielectric(code[4] as char)?;
+    let capacitance = parse_capacitance(&part_number[5..8])?;
+    let tolerance = parse_tolerance(&capacitance, code[8] as char)?;
+    let voltage = parse_voltage(&part_number[9..12])?;
+    parse_termination(code[12] as char)?;
+    let packaging = parse_packaging(code[13] as char)?;
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Walsin(Series::General),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade: Grade::Commercial,
+        features: Features::default(),
+        specification: None,
+        other: format!("{}{}", &part_number[12..13], &part_number[14..])
+    })
+}
+
+/// Creates a Walsin part number, inverse of parse. Termination is standard (C), 7" reel.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    if capacitor.grade == Grade::Automotive {
+        return Err(Error::NoCode(Attribute::Grade));
+    }
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let dielectric = find_code(&['N', 'B', 'X', 'F'], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let voltage = find_code(&["2R5", "4R0", "6R3", "100", "160", "250", "350", "500", "630", "101", "201", "251", "501", "631", "102", "202", "302"],
+        &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    Ok(format!("{}{}{}{}{}CT",
+        dimensions.code(),
+        dielectric,
+        capacitance_code(&capacitor.capacitance)?,
+        tolerance,
+        voltage
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let c = parse("0603B104K500CT").unwrap();
+        assert_eq!(c.dimensions, EIAInchCode::_0603);
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(c.capacitance, Capacitance::from_nanofarads(100));
+        assert_eq!(c.tolerance, Tolerance::PM10);
+        assert_eq!(c.voltage, RatedVoltage::DC_50V);
+        assert_eq!(c.packaging, Packaging::Reel(Reel::new(180, Tape::Unspecified)));
+        assert_eq!(c.other, "C");
+        assert_eq!(parse("0402N1R0C500CT").unwrap().capacitance, Capacitance::from_picofarads(1));
+        assert_eq!(parse("0805X106K6R3CT").unwrap().voltage, RatedVoltage::DC_6V3);
+    }
+
+    #[test]
+    fn invalid_codes() {
+        assert!(matches!(parse("0603B104K500C"), Err(Error::InsufficientData)));
+        assert!(matches!(parse("0603Q104K500CT"), Err(Error::WrongDielectricCode)));
+        assert!(matches!(parse("0603B104K5X0CT"), Err(Error::WrongVoltageCode)));
+        assert!(matches!(parse("0603B104K500XT"), Err(Error::WrongProductCode)));
+        assert!(matches!(parse("0603B104K500CX"), Err(Error::WrongPackagingCode)));
+        assert!(matches!(parse("0603YC104KAT2A"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["0603B104K500CT", "0402N1R0C500CT", "0805X106K6R3CT", "1206B105K250CT"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,305 @@
+use crate::parts::{EIAInchCode, eia_tolerance, SizeCode, capacitors::{Attribute, Capacitor, Capacitance, Error, Features, Grade, Packaging, Reel, Tape, parse_capacitance, capacitance_code, find_code}, Dielectric, Height, RatedVoltage, Tolerance};
+use strum_macros::EnumString;
+use std::str::FromStr;
+
+#[derive(EnumString, Debug, Copy, Clone, PartialEq)]
+pub enum Series {
+    /// General purpose
+    CC,
+    /// Automotive grade
+    AC,
+}
+
+/// Yageo uses the EIA inch code directly
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Dimensions {
+    _0201,
+    _0402,
+    _0603,
+    _0805,
+    _1206,
+    _1210,
+    _1808,
+    _1812,
+    _2220,
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl Dimensions {
+    /// Size code as written in the part number
+    pub fn code(&self) -> &'static str {
+        use Dimensions::*;
+        match self {
+            _0201 => "0201",
+            _0402 => "0402",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1808 => "1808",
+            _1812 => "1812",
+            _2220 => "2220",
+        }
+    }
+}
+
+impl FromStr for Dimensions {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dimensions::*;
+        match s {
+            "0201" => Ok(_0201),
+            "0402" => Ok(_0402),
+            "0603" => Ok(_0603),
+            "0805" => Ok(_0805),
+            "1206" => Ok(_1206),
+            "1210" => Ok(_1210),
+            "1808" => Ok(_1808),
+            "1812" => Ok(_1812),
+            "2220" => Ok(_2220),
+            _ => Err(Error::WrongDimensionCode)
+        }
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl SizeCode for Dimensions {
+    type MFCode = Self;
+
+    fn to_eia(code: Self) -> EIAInchCode {
+        use Dimensions::*;
+        match code {
+            _0201 => { EIAInchCode::_0201 },
+            _0402 => { EIAInchCode::_0402 },
+            _0603 => { EIAInchCode::_0603 },
+            _0805 => { EIAInchCode::_0805 },
+            _1206 => { EIAInchCode::_1206 },
+            _1210 => { EIAInchCode::_1210 },
+            _1808 => { EIAInchCode::_1808 },
+            _1812 => { EIAInchCode::_1812 },
+            _2220 => { EIAInchCode::_2220 },
+        }
+    }
+
+    fn to_mfcode(size: EIAInchCode) -> Option<Self> {
+        use Dimensions::*;
+        match size {
+            EIAInchCode::_0201 => { Some(_0201) },
+            EIAInchCode::_0402 => { Some(_0402) },
+            EIAInchCode::_0603 => { Some(_0603) },
+            EIAInchCode::_0805 => { Some(_0805) },
+            EIAInchCode::_1206 => { Some(_1206) },
+            EIAInchCode::_1210 => { Some(_1210) },
+            EIAInchCode::_1808 => { Some(_1808) },
+            EIAInchCode::_1812 => { Some(_1812) },
+            EIAInchCode::_2220 => { Some(_2220) },
+            _ => None
+        }
+    }
+}
+
+pub fn parse_tolerance(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    match code {
+        'B' | 'C' | 'D' | 'F' | 'G' | 'J' | 'K' | 'M' | 'Z' => eia_tolerance::parse(capacitance, code),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+pub fn parse_packaging(code: char) -> Result<Packaging, Error> {
+    match code {
+        'R' => Ok(Packaging::Reel(Reel::new(180, Tape::Paper))),
+        'K' => Ok(Packaging::Reel(Reel::new(180, Tape::Embossed))),
+        'P' => Ok(Packaging::Reel(Reel::new(330, Tape::Paper))),
+        'F' => Ok(Packaging::Reel(Reel::new(330, Tape::Embossed))),
+        'C' => Ok(Packaging::BulkCase),
+        _ => Err(Error::WrongPackagingCode)
+    }
+}
+
+pub fn parse_dielectric(code: &str) -> Result<Dielectric, Error> {
+    use Dielectric::*;
+    match code {
+        "NPO" => Ok(C0G),
+        "COG" => Ok(C0G),
+        "C0G" => Ok(C0G),
+        "X5R" => Ok(X5R),
+        "X6S" => Ok(X6S),
+        "X7R" => Ok(X7R),
+        "X7S" => Ok(X7S),
+        "X8R" => Ok(X8R),
+        "Y5V" => Ok(Y5V),
+        _ => Err(Error::WrongDielectricCode)
+    }
+}
+
+/// Single character voltage code, unlike the two character JIS code
+pub fn parse_voltage(code: char) -> Result<RatedVoltage, Error> {
+    use RatedVoltage::*;
+    match code {
+        '4' => Ok(DC_4V),
+        '5' => Ok(DC_6V3),
+        '6' => Ok(DC_10V),
+        '7' => Ok(DC_16V),
+        '8' => Ok(DC_25V),
+        '9' => Ok(DC_50V),
+        '0' => Ok(DC_100V),
+        'A' => Ok(DC_200V),
+        'Y' => Ok(DC_250V),
+        'B' => Ok(DC_500V),
+        'Z' => Ok(DC_630V),
+        'C' => Ok(DC_1kV),
+        'D' => Ok(DC_2kV),
+        'E' => Ok(DC_3kV),
+        _ => Err(Error::WrongVoltageCode)
+    }
+}
+
+/// Fixed width fields of a Yageo part number, dashes and spaces removed
+struct Segments<'a> {
+    size: &'a str,
+    tolerance: char,
+    packaging: char,
+    dielectric: &'a str,
+    voltage: char,
+    process: &'a str,
+    capacitance: &'a str,
+}
+
+/// BOMs and distributor listings sometimes split the number into groups (CC0603-KRX7R-9BB104),
+/// segments are taken from the concatenated code.
+fn tokenize(part_number: &str) -> Result<String, Error> {
+    let code: String = part_number.chars()
+        .filter(|c| *c != '-' && !c.is_whitespace())
+        .collect();
+    if !code.is_ascii() {
+        return Err(Error::UnknownSeries);
+    }
+    Ok(code)
+}
+
+fn split(code: &str) -> Segments<'_> {
+    let b = code.as_bytes();
+    Segments {
+        size: &code[2..6],
+        tolerance: b[6] as char,
+        packaging: b[7] as char,
+        dielectric: &code[8..11],
+        voltage: b[11] as char,
+        process: &code[12..14],
+        capacitance: &code[14..17],
+    }
+}
+
+/// CC0603KRX7R9BB104
+/// CC | 0603 | K | R | X7R | 9 | BB | 104
+/// series, size, tolerance, packaging, dielectric, voltage, process code, capacitance
+pub fn parse(part_number: &str) -> Result<Capacitor, Error> {
+    let code = tokenize(part_number)?;
+    let len = code.len();
+    if len < 2 {
+        return Err(Error::UnknownSeries);
+    }
+    let series = Series::from_str(&code[0..2]).map_err(|_| Error::UnknownSeries)?;
+    if len < 6 || !code[2..6].bytes().all(|c| c.is_ascii_digit()) {
+        return Err(Error::UnknownSeries);
+    }
+    if len < 17 {
+        return Err(Error::InsufficientData);
+    }
+    let segments = split(&code);
+    let dimensions = Dimensions::from_str(segments.size)?;
+    let packaging = parse_packaging(segments.packaging)?;
+    let dielectric = parse_dielectric(segments.dielectric)?;
+    let voltage = parse_voltage(segments.voltage)?;
+    let capacitance = parse_capacitance(segments.capacitance)?;
+    let tolerance = parse_tolerance(&capacitance, segments.tolerance)?;
+    let grade = match series {
+        Series::AC => Grade::Automotive,
+        Series::CC => Grade::Commercial
+    };
+
+    Ok(Capacitor{
+        series: crate::parts::capacitors::Series::Yageo(series),
+        dimensions: Dimensions::to_eia(dimensions),
+        max_height: Height::Unknown,
+        dielectric,
+        voltage,
+        capacitance,
+        tolerance,
+        packaging,
+        grade,
+        features: Features::default(),
+        specification: None,
+        other: format!("{}{}", segments.process, &code[17..])
+    })
+}
+
+/// Creates a Yageo part number, inverse of parse.
+/// Packaging defaults to 7" paper reel, process code is BN for class I and BB otherwise.
+pub fn encode(capacitor: &Capacitor) -> Result<String, Error> {
+    let series = match capacitor.grade {
+        Grade::Commercial => Series::CC,
+        Grade::Automotive => Series::AC
+    };
+    let dimensions = Dimensions::to_mfcode(capacitor.dimensions).ok_or(Error::NoCode(Attribute::Dimensions))?;
+    let tolerance = find_code(&['B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'Z'], &capacitor.tolerance,
+        |c| parse_tolerance(&capacitor.capacitance, c), Attribute::Tolerance)?;
+    let packaging = find_code(&['R', 'K', 'P', 'F', 'C'], &capacitor.packaging.normalized(), parse_packaging, Attribute::Packaging)
+        .unwrap_or('R');
+    let dielectric = find_code(&["NPO", "X5R", "X6S", "X7R", "X7S", "X8R", "Y5V"], &capacitor.dielectric, parse_dielectric, Attribute::Dielectric)?;
+    let voltage = find_code(&['4', '5', '6', '7', '8', '9', '0', 'A', 'Y', 'B', 'Z', 'C', 'D', 'E'],
+        &capacitor.voltage, parse_voltage, Attribute::Voltage)?;
+    let process = if capacitor.dielectric == Dielectric::C0G { "BN" } else { "BB" };
+    Ok(format!("{:?}{}{}{}{}{}{}{}",
+        series,
+        dimensions.code(),
+        tolerance,
+        packaging,
+        dielectric,
+        voltage,
+        process,
+        capacitance_code(&capacitor.capacitance)?
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        let c = parse("CC0603KRX7R9BB104").unwrap();
+        assert!(matches!(c.series, crate::parts::capacitors::Series::Yageo(Series::CC)));
+        assert_eq!(c.dimensions, EIAInchCode::_0603);
+        assert_eq!(c.dielectric, Dielectric::X7R);
+        assert_eq!(c.voltage, RatedVoltage::DC_50V);
+        assert_eq!(c.capacitance, Capacitance::from_nanofarads(100));
+        assert_eq!(c.tolerance, Tolerance::PM10);
+        assert_eq!(c.packaging, Packaging::Reel(Reel::new(180, Tape::Paper)));
+        assert_eq!(c.grade, Grade::Commercial);
+        assert_eq!(c.other, "BB");
+        assert_eq!(parse("AC0402JRNPO9BN101").unwrap().grade, Grade::Automotive);
+        let grouped = parse("CC0603-KRX7R-9BB104").unwrap();
+        assert_eq!(grouped.capacitance, c.capacitance);
+        assert_eq!(grouped.other, c.other);
+    }
+
+    #[test]
+    fn invalid_codes() {
+        assert!(matches!(parse("CC0603KRX7R9BB10"), Err(Error::InsufficientData)));
+        assert!(matches!(parse("CC0604KRX7R9BB104"), Err(Error::WrongDimensionCode)));
+        assert!(matches!(parse("CC0603KQX7R9BB104"), Err(Error::WrongPackagingCode)));
+        assert!(matches!(parse("CC0603KRX9R9BB104"), Err(Error::WrongDielectricCode)));
+        assert!(matches!(parse("CC0603KRX7R1BB104"), Err(Error::WrongVoltageCode)));
+        assert!(matches!(parse("CC0603QRX7R9BB104"), Err(Error::WrongToleranceCode)));
+        assert!(matches!(parse("CD0603KRX7R9BB104"), Err(Error::UnknownSeries)));
+    }
+
+    #[test]
+    fn round_trip() {
+        for pn in &["CC0603KRX7R9BB104", "CC0402KRX5R6BB104", "AC0402JRNPO9BN101", "CC1206KKX7R0BB105", "CC0805ZRY5V7BB225"] {
+            assert_eq!(encode(&parse(pn).unwrap()).unwrap(), *pn);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Ordering;
+
+/// IEC 60063 preferred number series
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ESeries {
+    E3,
+    E6,
+    E12,
+    E24,
+    E48,
+    E96,
+    E192,
+}
+
+/// Significands of one decade with two digits, E3 to E12 are every 8th, 4th and 2nd value
+const E24_TABLE: [u16; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30,
+    33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+/// Significands of one decade with three digits, E48 and E96 are every 4th and 2nd value
+const E192_TABLE: [u16; 192] = [
+    100, 101, 102, 104, 105, 106, 107, 109, 110, 111, 113, 114, 115, 117, 118, 120,
+    121, 123, 124, 126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 142, 143, 145,
+    147, 149, 150, 152, 154, 156, 158, 160, 162, 164, 165, 167, 169, 172, 174, 176,
+    178, 180, 182, 184, 187, 189, 191, 193, 196, 198, 200, 203, 205, 208, 210, 213,
+    215, 218, 221, 223, 226, 229, 232, 234, 237, 240, 243, 246, 249, 252, 255, 258,
+    261, 264, 267, 271, 274, 277, 280, 284, 287, 291, 294, 298, 301, 305, 309, 312,
+    316, 320, 324, 328, 332, 336, 340, 344, 348, 352, 357, 361, 365, 370, 374, 379,
+    383, 388, 392, 397, 402, 407, 412, 417, 422, 427, 432, 437, 442, 448, 453, 459,
+    464, 470, 475, 481, 487, 493, 499, 505, 511, 517, 523, 530, 536, 542, 549, 556,
+    562, 569, 576, 583, 590, 597, 604, 612, 619, 626, 634, 642, 649, 657, 665, 673,
+    681, 690, 698, 706, 715, 723, 732, 741, 750, 759, 768, 777, 787, 796, 806, 816,
+    825, 835, 845, 856, 866, 876, 887, 898, 909, 920, 931, 942, 953, 965, 976, 988,
+];
+
+impl ESeries {
+    /// Significands of one decade scaled to three digits: 100 = 1.00, 470 = 4.70
+    pub fn significands(&self) -> impl Iterator<Item = u16> {
+        use ESeries::*;
+        let (table, step): (&'static [u16], usize) = match self {
+            E3 => (&E24_TABLE, 8),
+            E6 => (&E24_TABLE, 4),
+            E12 => (&E24_TABLE, 2),
+            E24 => (&E24_TABLE, 1),
+            E48 => (&E192_TABLE, 4),
+            E96 => (&E192_TABLE, 2),
+            E192 => (&E192_TABLE, 1),
+        };
+        let scale = if table.len() == 24 { 10 } else { 1 };
+        table.iter().step_by(step).map(move |s| s * scale)
+    }
+
+    /// Splits a positive value into a three digit significand and a decimal exponent,
+    /// None if it has more than three significant digits
+    fn split(value: u64) -> Option<(u16, u32)> {
+        let mut significand = value;
+        let mut exponent = 0;
+        while significand >= 1000 {
+            if !significand.is_multiple_of(10) {
+                return None;
+            }
+            significand /= 10;
+            exponent += 1;
+        }
+        if significand < 100 {
+            return None;
+        }
+        Some((significand as u16, exponent))
+    }
+
+    /// Value is a significand of the series times a power of ten, at least 100
+    pub fn contains(&self, value: u64) -> bool {
+        match Self::split(value) {
+            Some((significand, _)) => self.significands().any(|s| s == significand),
+            None => false
+        }
+    }
+
+    /// Nearest series value on a logarithmic scale, None for values below 100
+    pub fn nearest(&self, value: u64) -> Option<u64> {
+        if value < 100 {
+            return None;
+        }
+        let exponent = value.ilog10() - 2;
+        let decade = 10u64.pow(exponent);
+        let target = (value as f64).ln();
+        self.significands()
+            .map(|s| s as u64 * decade)
+            .chain(std::iter::once(1000 * decade))
+            .min_by(|a, b| {
+                let da = ((*a as f64).ln() - target).abs();
+                let db = ((*b as f64).ln() - target).abs();
+                da.partial_cmp(&db).unwrap_or(Ordering::Equal)
+            })
+    }
+
+    /// Series values in `from..=to`, ascending, starting at 100
+    pub fn range(&self, from: u64, to: u64) -> impl Iterator<Item = u64> {
+        let significands: Vec<u16> = self.significands().collect();
+        (0..=u64::MAX.ilog10())
+            .map(|e| 10u64.pow(e))
+            .flat_map(move |decade| {
+                significands.clone().into_iter().filter_map(move |s| (s as u64).checked_mul(decade))
+            })
+            .skip_while(move |v| *v < from)
+            .take_while(move |v| *v <= to)
+    }
+}
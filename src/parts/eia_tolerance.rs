@@ -0,0 +1,32 @@
+use crate::parts::{Tolerance, capacitors::{Attribute, Capacitance, Error, find_code}};
+
+/// IEC 60062 tolerance letters. Below 10pF D, F and G are absolute: ±0.5pF, ±1pF, ±2pF
+pub fn parse(capacitance: &Capacitance, code: char) -> Result<Tolerance, Error> {
+    use Tolerance::*;
+    let below_10pf = *capacitance < Capacitance::from_picofarads(10);
+    match code {
+        'W' => Ok(PM0pF05),
+        'B' => Ok(PM0pF1),
+        'C' => Ok(PM0pF25),
+        'D' if below_10pf => Ok(PM0pF5),
+        'D' => Ok(PM05),
+        'F' if below_10pf => Ok(PM1pF),
+        'F' => Ok(PM1),
+        'G' if below_10pf => Ok(FemtoFarads { minus: 2000, plus: 2000 }),
+        'G' => Ok(PM2),
+        'J' => Ok(PM5),
+        'K' => Ok(PM10),
+        'M' => Ok(PM20),
+        'P' => Ok(Percent { minus: 0, plus: 100 }),
+        'Q' => Ok(Percent { minus: 10, plus: 30 }),
+        'S' => Ok(Percent { minus: 20, plus: 50 }),
+        'T' => Ok(Percent { minus: 10, plus: 50 }),
+        'Z' => Ok(Percent { minus: 20, plus: 80 }),
+        _ => Err(Error::WrongToleranceCode)
+    }
+}
+
+pub fn encode(capacitance: &Capacitance, tolerance: &Tolerance) -> Result<char, Error> {
+    find_code(&['W', 'B', 'C', 'D', 'F', 'G', 'J', 'K', 'M', 'P', 'Q', 'S', 'T', 'Z'], tolerance,
+        |c| parse(capacitance, c), Attribute::Tolerance)
+}
@@ -0,0 +1,103 @@
+use crate::parts::{RatedVoltage, capacitors::{Attribute, Error}};
+
+/// JIS C 5101 / IEC 60062 rated voltage codes: decade digit and significand letter,
+/// A = 1.0, B = 1.25, C = 1.6, D = 2.0, E = 2.5, F = 3.15, V = 3.5, G = 4.0, W = 4.5, H = 5.0,
+/// J = 6.3, K = 8.0. 0J = 6.3V, 1H = 50V, 2A = 100V, 3A = 1kV
+const CODES: [(&str, RatedVoltage); 48] = {
+    use RatedVoltage::*;
+    [
+        ("0A", DC_1V),
+        ("0B", CustomDC(1_250)),
+        ("0C", DC_1V6),
+        ("0D", CustomDC(2_000)),
+        ("0E", DC_2V5),
+        ("0F", CustomDC(3_150)),
+        ("0V", CustomDC(3_500)),
+        ("0G", DC_4V),
+        ("0W", CustomDC(4_500)),
+        ("0H", CustomDC(5_000)),
+        ("0J", DC_6V3),
+        ("0K", CustomDC(8_000)),
+        ("1A", DC_10V),
+        ("1B", CustomDC(12_500)),
+        ("1C", DC_16V),
+        ("1D", CustomDC(20_000)),
+        ("1E", DC_25V),
+        ("1F", CustomDC(31_500)),
+        ("1V", DC_35V),
+        ("1G", CustomDC(40_000)),
+        ("1W", CustomDC(45_000)),
+        ("1H", DC_50V),
+        ("1J", DC_63V),
+        ("1K", DC_80V),
+        ("2A", DC_100V),
+        ("2B", CustomDC(125_000)),
+        ("2C", CustomDC(160_000)),
+        ("2D", DC_200V),
+        ("2E", DC_250V),
+        ("2F", CustomDC(315_000)),
+        ("2V", CustomDC(350_000)),
+        ("2G", CustomDC(400_000)),
+        ("2W", DC_450V),
+        ("2H", DC_500V),
+        ("2J", DC_630V),
+        ("2K", CustomDC(800_000)),
+        ("3A", DC_1kV),
+        ("3B", CustomDC(1_250_000)),
+        ("3C", CustomDC(1_600_000)),
+        ("3D", DC_2kV),
+        ("3E", CustomDC(2_500_000)),
+        ("3F", DC_3kV15),
+        ("3V", CustomDC(3_500_000)),
+        ("3G", CustomDC(4_000_000)),
+        ("3W", CustomDC(4_500_000)),
+        ("3H", CustomDC(5_000_000)),
+        ("3J", CustomDC(6_300_000)),
+        ("3K", CustomDC(8_000_000)),
+    ]
+};
+
+pub fn parse(code: &str) -> Result<RatedVoltage, Error> {
+    CODES.iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, v)| *v)
+        .ok_or(Error::WrongVoltageCode)
+}
+
+/// Inverse of parse, custom voltages match their named variant: CustomDC(3_150_000) = 3F.
+/// Ratings between the significands, e.g. 3kV, have no code.
+pub fn encode(voltage: &RatedVoltage) -> Result<&'static str, Error> {
+    CODES.iter()
+        .find(|(_, v)| v == voltage)
+        .map(|(c, _)| *c)
+        .ok_or(Error::NoCode(Attribute::Voltage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn codes() {
+        for (text, code) in &[("6.3V", "0J"), ("6V3", "0J"), ("50VDC", "1H"), ("3.15 kV", "3F"), ("1V6", "0C")] {
+            let voltage = RatedVoltage::from_str(text).unwrap();
+            assert_eq!(encode(&voltage).unwrap(), *code);
+            assert_eq!(parse(code).unwrap(), voltage);
+        }
+        assert!(matches!(encode(&RatedVoltage::AC_250V), Err(Error::NoCode(Attribute::Voltage))));
+    }
+
+    #[test]
+    fn every_significand() {
+        for (code, millivolts) in &[("0B", 1_250), ("0F", 3_150), ("0V", 3_500), ("0W", 4_500), ("1B", 12_500),
+                                    ("1F", 31_500), ("1W", 45_000), ("3K", 8_000_000)] {
+            let voltage = parse(code).unwrap();
+            assert_eq!(voltage, RatedVoltage::CustomDC(*millivolts));
+            assert_eq!(encode(&voltage).unwrap(), *code);
+        }
+        assert_eq!(encode(&RatedVoltage::from_str("1.25V").unwrap()).unwrap(), "0B");
+        assert!(matches!(encode(&RatedVoltage::DC_3kV), Err(Error::NoCode(Attribute::Voltage))));
+        assert_eq!(parse("3F").unwrap(), RatedVoltage::DC_3kV15);
+    }
+}
@@ -1,8 +1,18 @@
 pub mod capacitors;
+pub mod e_series;
+pub mod jis_voltage;
+pub mod eia_tolerance;
+pub mod package;
+use capacitors::{Capacitance, Error};
 use std::fmt;
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::str::FromStr;
 use std::fmt::Formatter;
+use strum::IntoEnumIterator;
+use strum_macros::EnumIter;
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(EnumIter, Debug, Copy, Clone, PartialEq)]
 pub enum IECMetricCode {
     _0201,
     _03015,
@@ -46,16 +56,15 @@ pub enum IECMetricCode {
 
 impl fmt::Display for IECMetricCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let d = format!("{:?}", self);
         if f.alternate() {
-            write!(f, "{} Metric", &d[1..])
+            write!(f, "{} Metric", self.code())
         } else {
-            write!(f, "{}", &d[1..])
+            write!(f, "{}", self.code())
         }
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(EnumIter, Debug, Copy, Clone, PartialEq)]
 pub enum EIAInchCode {
     _008004,
     _009005,
@@ -97,11 +106,153 @@ pub enum EIAInchCode {
 
 impl fmt::Display for EIAInchCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        let d = format!("{:?}", self);
         if f.alternate() {
-            write!(f, "{} Inch", &d[1..])
+            write!(f, "{} Inch", self.code())
         } else {
-            write!(f, "{}", &d[1..])
+            write!(f, "{}", self.code())
+        }
+    }
+}
+
+/// Unit system of a size code
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SizeSystem {
+    Metric,
+    Inch,
+}
+
+/// Splits the unit off a size code: 1608M, 1608 Metric, 0603 Inch, 0603in. The system is None when
+/// the text does not name one.
+pub(crate) fn split_size_code(s: &str) -> (String, Option<SizeSystem>) {
+    let s: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+    for (suffix, system) in &[("metric", SizeSystem::Metric), ("m", SizeSystem::Metric),
+        ("inch", SizeSystem::Inch), ("in", SizeSystem::Inch)] {
+        if let Some(code) = s.strip_suffix(suffix) {
+            return (code.to_string(), Some(*system));
+        }
+    }
+    (s, None)
+}
+
+/// 1608, 1608M or 1608 Metric, inch codes are converted when unique: 0603 Inch, or a bare 1206
+/// that is not a metric code
+#[allow(clippy::just_underscores_and_digits)]
+impl IECMetricCode {
+    /// Digits of the size code, 1608 for _1608
+    pub fn code(&self) -> &'static str {
+        use IECMetricCode::*;
+        match self {
+            _0201 => "0201",
+            _03015 => "03015",
+            _0402 => "0402",
+            _0404 => "0404",
+            _0505 => "0505",
+            _0603 => "0603",
+            _0610 => "0610",
+            _0805 => "0805",
+            _0808 => "0808",
+            _1005 => "1005",
+            _1310 => "1310",
+            _1608 => "1608",
+            _2012 => "2012",
+            _2520 => "2520",
+            _2828 => "2828",
+            _3216 => "3216",
+            _3225 => "3225",
+            _3625 => "3625",
+            _3838 => "3838",
+            _4516 => "4516",
+            _4520 => "4520",
+            _4532 => "4532",
+            _4564 => "4564",
+            _5025 => "5025",
+            _5050 => "5050",
+            _5664 => "5664",
+            _5728 => "5728",
+            _5750 => "5750",
+            _5764 => "5764",
+            _6332 => "6332",
+            _6432 => "6432",
+            _6450 => "6450",
+            _7450 => "7450",
+            _8484 => "8484",
+            _9210 => "9210",
+            _100100 => "100100",
+            _140127 => "140127",
+            _203153 => "203153",
+        }
+    }
+}
+
+impl FromStr for IECMetricCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split_size_code(s) {
+            (code, Some(SizeSystem::Inch)) => EIAInchCode::from_str(&format!("{} Inch", code))?.to_metric(),
+            (code, system) => match IECMetricCode::iter().find(|c| c.to_string() == code) {
+                Some(metric) => Ok(metric),
+                None if system.is_none() => EIAInchCode::from_str(&format!("{} Inch", code))?.to_metric(),
+                None => Err(Error::WrongDimensionCode),
+            }
+        }
+    }
+}
+
+/// 0603 or 0603 Inch, metric codes are converted when marked: 1608M
+#[allow(clippy::just_underscores_and_digits)]
+impl EIAInchCode {
+    /// Digits of the size code, 0603 for _0603
+    pub fn code(&self) -> &'static str {
+        use EIAInchCode::*;
+        match self {
+            _008004 => "008004",
+            _009005 => "009005",
+            _01005 => "01005",
+            _015015 => "015015",
+            _0201 => "0201",
+            _0202 => "0202",
+            _02404 => "02404",
+            _0302 => "0302",
+            _0303 => "0303",
+            _0402 => "0402",
+            _0504 => "0504",
+            _0603 => "0603",
+            _0805 => "0805",
+            _1008 => "1008",
+            _1111 => "1111",
+            _1206 => "1206",
+            _1210 => "1210",
+            _1410 => "1410",
+            _1515 => "1515",
+            _1806 => "1806",
+            _1808 => "1808",
+            _1812 => "1812",
+            _1825 => "1825",
+            _2010 => "2010",
+            _2020 => "2020",
+            _2211 => "2211",
+            _2220 => "2220",
+            _2225 => "2225",
+            _2512 => "2512",
+            _2520 => "2520",
+            _2920 => "2920",
+            _3333 => "3333",
+            _3640 => "3640",
+            _4040 => "4040",
+            _5550 => "5550",
+            _8060 => "8060",
+        }
+    }
+}
+
+impl FromStr for EIAInchCode {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match split_size_code(s) {
+            (code, Some(SizeSystem::Metric)) => IECMetricCode::from_str(&format!("{}M", code)).map(EIAInchCode::from),
+            (code, _) => EIAInchCode::iter().find(|c| c.to_string() == code).ok_or(Error::WrongDimensionCode)
         }
     }
 }
@@ -194,6 +345,41 @@ impl From<EIAInchCode> for IECMetricCode {
     }
 }
 
+
+impl EIAInchCode {
+    /// Every metric code of this size: 2225 is 5664 or 5764, 2512 is 6332 or 6432
+    pub fn metric_candidates(&self) -> Vec<IECMetricCode> {
+        IECMetricCode::iter().filter(|m| EIAInchCode::from(*m) == *self).collect()
+    }
+
+    /// Metric code if there is only one, unlike `IECMetricCode::from` which picks the common one
+    pub fn to_metric(self) -> Result<IECMetricCode, Error> {
+        match self.metric_candidates().as_slice() {
+            [metric] => Ok(*metric),
+            _ => Err(Error::AmbiguousSize)
+        }
+    }
+}
+
+/// Decimal number with '.' separator multiplied by `scale`, None if it does not come out whole
+pub(crate) fn parse_decimal(number: &str, scale: u64) -> Option<u64> {
+    let (decimal, fractional) = match number.find('.') {
+        Some(i) => (&number[..i], &number[i + 1..]),
+        None => (number, "")
+    };
+    if (decimal.is_empty() && fractional.is_empty())
+        || !decimal.bytes().chain(fractional.bytes()).all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let divisor = 10u64.checked_pow(fractional.len() as u32)?;
+    if !scale.is_multiple_of(divisor) {
+        return None;
+    }
+    let decimal: u64 = if decimal.is_empty() { 0 } else { decimal.parse().ok()? };
+    let fractional: u64 = if fractional.is_empty() { 0 } else { fractional.parse().ok()? };
+    decimal.checked_mul(scale)?.checked_add(fractional * (scale / divisor))
+}
+
 pub trait SizeCode {
     type MFCode;
 
@@ -201,9 +387,13 @@ pub trait SizeCode {
     fn to_mfcode(size: EIAInchCode) -> Option<Self::MFCode>;
 }
 
+/// Named variants for the common ratings, custom values in millivolts. Equality and ordering are
+/// numeric, so CustomDC(50_000) equals DC_50V, and all DC ratings order before AC ratings.
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone)]
 pub enum RatedVoltage {
+    DC_1V,
+    DC_1V6,
     DC_2V5,
     DC_4V,
     DC_6V3,
@@ -213,6 +403,7 @@ pub enum RatedVoltage {
     DC_35V,
     DC_50V,
     DC_63V,
+    DC_80V,
     DC_100V,
     DC_200V,
     DC_250V,
@@ -224,56 +415,174 @@ pub enum RatedVoltage {
     DC_3kV,
     DC_3kV15,
     AC_250V,
-    CustomDC(u32),
-    CustomAC(u32)
+    /// Millivolts DC
+    CustomDC(u64),
+    /// Millivolts AC
+    CustomAC(u64)
 }
 
-impl fmt::Display for RatedVoltage {
-    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+impl RatedVoltage {
+    /// All voltages with a named variant, ascending, AC last
+    pub const STANDARD: [RatedVoltage; 23] = {
         use RatedVoltage::*;
-        let (decimal, fractional, is_dc) = match self {
-            DC_2V5 => (2, 5, true),
-            DC_4V => (4, 0, true),
-            DC_6V3 => (6, 3, true),
-            DC_10V => (10, 0, true),
-            DC_16V => (16, 0, true),
-            DC_25V => (25, 0, true),
-            DC_35V => (35, 0, true),
-            DC_50V => (50, 0, true),
-            DC_63V => (63, 0, true),
-            DC_100V => (100, 0, true),
-            DC_200V => (200, 0, true),
-            DC_250V => (250, 0, true),
-            DC_450V => (450, 0, true),
-            DC_500V => (500, 0, true),
-            DC_630V => (630, 0, true),
-            DC_1kV => (1000, 0, true),
-            DC_2kV => (2000, 0, true),
-            DC_3kV => (3000, 0, true),
-            DC_3kV15 => (3150, 0, true),
-            AC_250V => (250, 0, false),
-            CustomDC(dc) => (*dc, 0, true),
-            CustomAC(ac) => (*ac, 0, false),
+        [DC_1V, DC_1V6, DC_2V5, DC_4V, DC_6V3, DC_10V, DC_16V, DC_25V, DC_35V, DC_50V, DC_63V, DC_80V,
+            DC_100V, DC_200V, DC_250V, DC_450V, DC_500V, DC_630V, DC_1kV, DC_2kV, DC_3kV, DC_3kV15, AC_250V]
+    };
+
+    pub fn millivolts(&self) -> u64 {
+        use RatedVoltage::*;
+        match self {
+            DC_1V => 1_000,
+            DC_1V6 => 1_600,
+            DC_2V5 => 2_500,
+            DC_4V => 4_000,
+            DC_6V3 => 6_300,
+            DC_10V => 10_000,
+            DC_16V => 16_000,
+            DC_25V => 25_000,
+            DC_35V => 35_000,
+            DC_50V => 50_000,
+            DC_63V => 63_000,
+            DC_80V => 80_000,
+            DC_100V => 100_000,
+            DC_200V => 200_000,
+            DC_250V => 250_000,
+            DC_450V => 450_000,
+            DC_500V => 500_000,
+            DC_630V => 630_000,
+            DC_1kV => 1_000_000,
+            DC_2kV => 2_000_000,
+            DC_3kV => 3_000_000,
+            DC_3kV15 => 3_150_000,
+            AC_250V => 250_000,
+            CustomDC(mv) => *mv,
+            CustomAC(mv) => *mv,
+        }
+    }
+
+    pub fn volts(&self) -> f64 {
+        self.millivolts() as f64 / 1000.0
+    }
+
+    pub fn is_dc(&self) -> bool {
+        !matches!(self, RatedVoltage::AC_250V | RatedVoltage::CustomAC(_))
+    }
+
+    /// Named variant if there is one, custom otherwise
+    pub fn from_millivolts(millivolts: u64, dc: bool) -> RatedVoltage {
+        Self::STANDARD.iter()
+            .find(|v| v.millivolts() == millivolts && v.is_dc() == dc)
+            .copied()
+            .unwrap_or(if dc { RatedVoltage::CustomDC(millivolts) } else { RatedVoltage::CustomAC(millivolts) })
+    }
+
+    /// DC rating rounded to millivolts: RatedVoltage::dc(12.0) * 2.0
+    pub fn dc(volts: f64) -> RatedVoltage {
+        Self::from_millivolts((volts * 1000.0).round() as u64, true)
+    }
+
+    /// AC rating rounded to millivolts
+    pub fn ac(volts: f64) -> RatedVoltage {
+        Self::from_millivolts((volts * 1000.0).round() as u64, false)
+    }
+
+    /// Same kind (DC or AC) and not lower than `required`, for derating checks:
+    /// part.voltage.at_least(RatedVoltage::dc(12.0) * 2.0)
+    pub fn at_least(&self, required: RatedVoltage) -> bool {
+        self.is_dc() == required.is_dc() && self.millivolts() >= required.millivolts()
+    }
+}
+
+impl PartialEq for RatedVoltage {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RatedVoltage {}
+
+impl PartialOrd for RatedVoltage {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RatedVoltage {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (!self.is_dc(), self.millivolts()).cmp(&(!other.is_dc(), other.millivolts()))
+    }
+}
+
+impl std::hash::Hash for RatedVoltage {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.is_dc().hash(state);
+        self.millivolts().hash(state);
+    }
+}
+
+/// Scales the rating, rounded to millivolts
+impl std::ops::Mul<f64> for RatedVoltage {
+    type Output = RatedVoltage;
+
+    fn mul(self, rhs: f64) -> RatedVoltage {
+        Self::from_millivolts((self.millivolts() as f64 * rhs).round() as u64, self.is_dc())
+    }
+}
+
+/// Accepts 6.3V, 6V3, 50VDC, 250VAC, 1kV, 3.15 kV, case insensitive, DC if not specified
+impl FromStr for RatedVoltage {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+        let (s, dc) = if let Some(s) = s.strip_suffix("DC") {
+            (s, true)
+        } else if let Some(s) = s.strip_suffix("AC") {
+            (s, false)
+        } else {
+            (s.as_str(), true)
         };
-        if fractional == 0 {
-            if is_dc {
-                write!(f, "{}V", decimal)
-            } else {
-                write!(f, "{}VAC", decimal)
-            }
+        let v = s.find('V').ok_or(Error::WrongVoltageCode)?;
+        let (number, fractional) = (&s[..v], &s[v + 1..]);
+        let (number, scale) = match number.strip_suffix('K') {
+            Some(number) => (number, 1_000_000),
+            None => (number, 1_000)
+        };
+        let millivolts = if fractional.is_empty() {
+            parse_decimal(number, scale)
+        } else if number.bytes().all(|c| c.is_ascii_digit()) { // 6V3
+            parse_decimal(&format!("{}.{}", number, fractional), scale)
         } else {
-            if f.alternate() {
-                write!(f, "{}V{}", decimal, fractional)
-            } else {
-                write!(f, "{}.{}V", decimal, fractional)
-            }
+            None
+        };
+        millivolts.map(|mv| Self::from_millivolts(mv, dc)).ok_or(Error::WrongVoltageCode)
+    }
+}
+
+impl fmt::Display for RatedVoltage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let millivolts = self.millivolts();
+        let decimal = millivolts / 1000;
+        let fractional = format!("{:03}", millivolts % 1000);
+        let fractional = fractional.trim_end_matches('0');
+        let suffix = if self.is_dc() { "" } else { "AC" };
+        if fractional.is_empty() {
+            write!(f, "{}V{}", decimal, suffix)
+        } else if f.alternate() {
+            write!(f, "{}V{}{}", decimal, fractional, suffix)
+        } else {
+            write!(f, "{}.{}V{}", decimal, fractional, suffix)
         }
     }
 }
 
+/// Capacitance tolerance, asymmetric variants have the lower deviation first.
+/// Equality is numeric, Percent { minus: 5, plus: 5 } equals PM5.
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone)]
 pub enum Tolerance {
+    /// ±0.25%
+    PM025,
     /// ±0.5%
     PM05,
     /// ±1%
@@ -290,8 +599,10 @@ pub enum Tolerance {
     PM10,
     /// ±20%
     PM20,
-    /// -.0% +.1%
-    Percent(u8, u8),
+    /// -minus% +plus%
+    Percent { minus: u8, plus: u8 },
+    /// ±0.05pF
+    PM0pF05,
     /// ±0.1pF
     PM0pF1,
     /// ±0.25pF
@@ -300,20 +611,151 @@ pub enum Tolerance {
     PM0pF5,
     /// ±1pF
     PM1pF,
-    /// -.0aF +.1aF
-    AttoFarads(u64, u64)
+    /// -minus fF +plus fF
+    FemtoFarads { minus: u64, plus: u64 },
+    /// Not given by the part number, e.g. Murata NFM
+    Unknown,
+}
+
+/// Tolerance as lower and upper deviation from the nominal value
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Deviation {
+    /// Hundredths of a percent
+    BasisPoints { minus: u64, plus: u64 },
+    FemtoFarads { minus: u64, plus: u64 },
+    Unknown,
+}
+
+impl Tolerance {
+    pub fn deviation(&self) -> Deviation {
+        use Tolerance::*;
+        let basis_points = |minus, plus| Deviation::BasisPoints { minus, plus };
+        let femto_farads = |minus, plus| Deviation::FemtoFarads { minus, plus };
+        match self {
+            PM025 => basis_points(25, 25),
+            PM05 => basis_points(50, 50),
+            PM1 => basis_points(100, 100),
+            PM2 => basis_points(200, 200),
+            P5 => basis_points(0, 500),
+            M5 => basis_points(500, 0),
+            PM5 => basis_points(500, 500),
+            PM10 => basis_points(1000, 1000),
+            PM20 => basis_points(2000, 2000),
+            Percent { minus, plus } => basis_points(*minus as u64 * 100, *plus as u64 * 100),
+            PM0pF05 => femto_farads(50, 50),
+            PM0pF1 => femto_farads(100, 100),
+            PM0pF25 => femto_farads(250, 250),
+            PM0pF5 => femto_farads(500, 500),
+            PM1pF => femto_farads(1000, 1000),
+            FemtoFarads { minus, plus } => femto_farads(*minus, *plus),
+            Unknown => Deviation::Unknown,
+        }
+    }
+
+    /// Named variant if there is one
+    pub fn from_deviation(deviation: Deviation) -> Option<Tolerance> {
+        use Tolerance::*;
+        const NAMED: [Tolerance; 14] = [PM025, PM05, PM1, PM2, P5, M5, PM5, PM10, PM20, PM0pF05, PM0pF1, PM0pF25, PM0pF5, PM1pF];
+        if let Some(t) = NAMED.iter().find(|t| t.deviation() == deviation) {
+            return Some(*t);
+        }
+        match deviation {
+            Deviation::BasisPoints { minus, plus } if minus.is_multiple_of(100) && plus.is_multiple_of(100) => {
+                Some(Percent {
+                    minus: u8::try_from(minus / 100).ok()?,
+                    plus: u8::try_from(plus / 100).ok()?
+                })
+            },
+            Deviation::BasisPoints { .. } => None,
+            Deviation::FemtoFarads { minus, plus } => Some(FemtoFarads { minus, plus }),
+            Deviation::Unknown => Some(Unknown),
+        }
+    }
+
+    /// Worst case minimum and maximum of the nominal value, anything for an unknown tolerance
+    pub fn bounds(&self, nominal: &Capacitance) -> (Capacitance, Capacitance) {
+        let c = nominal.femtofarads();
+        let (min, max) = match self.deviation() {
+            Deviation::BasisPoints { minus, plus } => {
+                let c = c as u128;
+                let min = c * 10_000u128.saturating_sub(minus as u128) / 10_000;
+                let max = c * (10_000 + plus as u128) / 10_000;
+                (min as u64, u64::try_from(max).unwrap_or(u64::MAX))
+            },
+            Deviation::FemtoFarads { minus, plus } => (c.saturating_sub(minus), c.saturating_add(plus)),
+            Deviation::Unknown => (0, u64::MAX)
+        };
+        (Capacitance::from_femtofarads(min), Capacitance::from_femtofarads(max))
+    }
+}
+
+impl PartialEq for Tolerance {
+    fn eq(&self, other: &Self) -> bool {
+        self.deviation() == other.deviation()
+    }
+}
+
+impl Eq for Tolerance {}
+
+/// Accepts ±5%, +-5%, +/-5%, 5%, -20+80%, +80-20%, ±0.25%, ±0.25pF. Fractional percentages other
+/// than ±0.25% and ±0.5% have no variant and are rejected.
+impl FromStr for Tolerance {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let (s, scale, relative) = if let Some(s) = s.strip_suffix('%') {
+            (s, 100, true)
+        } else if let Some(s) = s.strip_suffix("pF").or_else(|| s.strip_suffix("pf")).or_else(|| s.strip_suffix("PF")) {
+            (s, 1000, false)
+        } else {
+            return Err(Error::WrongToleranceCode);
+        };
+        let value = |v: &str| parse_decimal(v, scale).ok_or(Error::WrongToleranceCode);
+        let symmetric = s.strip_prefix('±').or_else(|| s.strip_prefix("+/-")).or_else(|| s.strip_prefix("+-"));
+        let (minus, plus) = if let Some(v) = symmetric {
+            (value(v)?, value(v)?)
+        } else if let Some(rest) = s.strip_prefix('-') {
+            let (minus, plus) = rest.split_once('+').ok_or(Error::WrongToleranceCode)?;
+            (value(minus)?, value(plus)?)
+        } else if let Some(rest) = s.strip_prefix('+') {
+            let (plus, minus) = rest.split_once('-').ok_or(Error::WrongToleranceCode)?;
+            (value(minus)?, value(plus)?)
+        } else {
+            (value(s)?, value(s)?)
+        };
+        let deviation = if relative {
+            Deviation::BasisPoints { minus, plus }
+        } else {
+            Deviation::FemtoFarads { minus, plus }
+        };
+        Tolerance::from_deviation(deviation).ok_or(Error::WrongToleranceCode)
+    }
+}
+
+/// Decimal with trailing zeros removed, value in units of 1/scale
+fn format_decimal(value: u64, scale: u64) -> String {
+    let fractional = value % scale;
+    if fractional == 0 {
+        return (value / scale).to_string();
+    }
+    let width = scale.ilog10() as usize;
+    let fractional = format!("{:0width$}", fractional, width = width);
+    format!("{}.{}", value / scale, fractional.trim_end_matches('0'))
 }
 
 impl fmt::Display for Tolerance {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if f.alternate() {
             match self {
+                Tolerance::PM025 => write!(f, "U"), // ultra precise
                 Tolerance::PM05 => write!(f, "U"), // ultra precise
                 Tolerance::PM1 => write!(f, "P"), // precise
                 Tolerance::PM2 => write!(f, "P"), // precise
                 Tolerance::PM5 => write!(f, "P"), // precise
                 Tolerance::PM10 => write!(f, "S"), // standard
                 Tolerance::PM20 => write!(f, "C"), // coarse
+                Tolerance::PM0pF05 => write!(f, "U"), // ultra precise
                 Tolerance::PM0pF1 => write!(f, "U"), // ultra precise
                 Tolerance::PM0pF25 =>write!(f, "P"), // precise
                 Tolerance::PM0pF5 => write!(f, "S"), // standard
@@ -321,56 +763,128 @@ impl fmt::Display for Tolerance {
                 _ => { write!(f, "") }
             }
         } else {
-            match self {
-                Tolerance::PM05 => write!(f, "±0.5%"),
-                Tolerance::PM1 => write!(f, "±1%"),
-                Tolerance::PM2 => write!(f, "±2%"),
-                Tolerance::P5 => write!(f, "+5%"),
-                Tolerance::M5 => write!(f, "-5%"),
-                Tolerance::PM5 => write!(f, "±5%"),
-                Tolerance::PM10 => write!(f, "±10%"),
-                Tolerance::PM20 => write!(f, "±20%"),
-                Tolerance::Percent(b, a) => {
-                    if a == b {
-                        write!(f, "±{}%", a)
-                    } else {
-                        write!(f, "-{}+{}%", a, b)
-                    }
-                },
-                Tolerance::PM0pF1 => write!(f, "±0.1pF"),
-                Tolerance::PM0pF25 => write!(f, "±0.25pF"),
-                Tolerance::PM0pF5 => write!(f, "±0.5pF"),
-                Tolerance::PM1pF => write!(f, "±1pF"),
-                Tolerance::AttoFarads(b, a) => {
-                    if a == b {
-                        write!(f, "±{}pF", a)
-                    } else {
-                        write!(f, "-{}+{}pF", a, b)
-                    }
-                },
+            let (minus, plus, scale, unit) = match self.deviation() {
+                Deviation::BasisPoints { minus, plus } => (minus, plus, 100, "%"),
+                Deviation::FemtoFarads { minus, plus } => (minus, plus, 1000, "pF"),
+                Deviation::Unknown => return Ok(()),
+            };
+            if minus == plus {
+                write!(f, "±{}{}", format_decimal(minus, scale), unit)
+            } else if minus == 0 {
+                write!(f, "+{}{}", format_decimal(plus, scale), unit)
+            } else if plus == 0 {
+                write!(f, "-{}{}", format_decimal(minus, scale), unit)
+            } else {
+                write!(f, "-{}+{}{}", format_decimal(minus, scale), format_decimal(plus, scale), unit)
             }
         }
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq)]
-pub struct Height {
-    pub decimal: u8,
-    pub fractional: u8
+/// Physical length stored as an integer number of micrometres
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Length(u32);
+
+impl Length {
+    pub const fn from_micrometres(um: u32) -> Self {
+        Length(um)
+    }
+
+    pub const fn from_millimetres(mm: u32) -> Self {
+        Length(mm * 1000)
+    }
+
+    /// Rounded to the nearest micrometre, fails if longer than u32::MAX micrometres
+    pub fn from_mils(mils: u32) -> Result<Self, Error> {
+        u64::from(mils).checked_mul(254)
+            .map(|um| (um + 5) / 10)
+            .and_then(|um| u32::try_from(um).ok())
+            .map(Length)
+            .ok_or(Error::WrongLength)
+    }
+
+    pub const fn micrometres(&self) -> u32 {
+        self.0
+    }
+
+    pub fn millimetres(&self) -> f64 {
+        self.0 as f64 / 1000.0
+    }
+
+    pub fn inches(&self) -> f64 {
+        self.0 as f64 / 25_400.0
+    }
+}
+
+/// Number followed by mm, um, µm, in or mil: 0.85mm, 850um, 0.033in. Inches are rounded to the
+/// nearest micrometre.
+impl FromStr for Length {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s: String = s.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_lowercase();
+        let i = s.find(|c: char| c.is_alphabetic()).ok_or(Error::WrongLength)?;
+        let (number, unit) = s.split_at(i);
+        let um = match unit {
+            "mm" => parse_decimal(number, 1000),
+            "um" | "µm" | "μm" => parse_decimal(number, 1),
+            // micro inches, 1in = 25400um
+            "in" => parse_decimal(number, 1_000_000).and_then(|uin| uin.checked_mul(254)).map(|x| (x + 5_000) / 10_000),
+            "mil" => parse_decimal(number, 1000).and_then(|uin| uin.checked_mul(254)).map(|x| (x + 5_000) / 10_000),
+            _ => None
+        };
+        um.and_then(|um| u32::try_from(um).ok()).map(Length).ok_or(Error::WrongLength)
+    }
+}
+
+/// Millimetres: 0.85mm, alternate form is inches to three places: 0.033in
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            let mils = (self.0 as u64 * 10 + 127) / 254;
+            write!(f, "{}.{:03}in", mils / 1000, mils % 1000)
+        } else {
+            let fractional = format!("{:03}", self.0 % 1000);
+            let fractional = fractional.trim_end_matches('0');
+            if fractional.is_empty() {
+                write!(f, "{}mm", self.0 / 1000)
+            } else {
+                write!(f, "{}.{}mm", self.0 / 1000, fractional)
+            }
+        }
+    }
+}
+
+/// Component thickness, part numbers give either the typical value or an upper limit
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Height {
+    /// Typical thickness, parts vary within the manufacturer's tolerance
+    Nominal(Length),
+    /// Upper limit
+    Maximum(Length),
+    /// Not encoded in the part number
+    #[default]
+    Unknown,
 }
 
 impl Height {
-    pub fn new(decimal: u8, fractional: u8) -> Self {
-        Height { decimal, fractional }
+    pub fn length(&self) -> Option<Length> {
+        match self {
+            Height::Nominal(l) | Height::Maximum(l) => Some(*l),
+            Height::Unknown => None
+        }
     }
 }
 
 impl fmt::Display for Height {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        if self.fractional == 0 {
-            write!(f, "{}mm", self.decimal)
-        } else {
-            write!(f, "{}.{}mm", self.decimal, self.fractional)
+        match self {
+            Height::Nominal(l) => l.fmt(f),
+            Height::Maximum(l) => {
+                write!(f, "max ")?;
+                l.fmt(f)
+            },
+            Height::Unknown => write!(f, "unknown")
         }
     }
 }
@@ -393,6 +907,217 @@ pub enum Dielectric {
     X7S,
     X7T,
     X7U,
+    X8R,
     R,
     Y5V,
-}
\ No newline at end of file
+}
+
+/// EIA-198 ceramic class
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DielectricClass {
+    /// Temperature compensating, linear and stable: C0G, U2J, JIS CH
+    I,
+    /// High permittivity: X7R, X5R, JIS B and R
+    II,
+    /// Highest permittivity, least stable: Y5V
+    III,
+}
+
+/// Capacitance change over the operating temperature range
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TemperatureCharacteristic {
+    /// Class I: nominal coefficient and tolerance in ppm/°C, 0±30 for C0G
+    Ppm { nominal: i16, tolerance: u16 },
+    /// Class II and III: maximum change in percent, -15+15 for X7R
+    Percent { minus: u8, plus: u8 },
+}
+
+impl Dielectric {
+    pub fn class(&self) -> DielectricClass {
+        use Dielectric::*;
+        match self {
+            SL | CH | CJ | UJ | CK | C0G | X8G | U2J => DielectricClass::I,
+            B | X5R | X6S | X6T | X7R | X7S | X7T | X7U | X8R | R => DielectricClass::II,
+            Y5V => DielectricClass::III,
+        }
+    }
+
+    /// Operating temperature range in °C
+    pub fn temperature_range(&self) -> (i16, i16) {
+        use Dielectric::*;
+        match self {
+            C0G | U2J | X7R | X7S | X7T | X7U | R => (-55, 125),
+            X8G | X8R => (-55, 150),
+            X5R => (-55, 85),
+            X6S | X6T => (-55, 105),
+            SL | CH | CJ | UJ | CK | B => (-25, 85),
+            Y5V => (-30, 85),
+        }
+    }
+
+    pub fn temperature_characteristic(&self) -> TemperatureCharacteristic {
+        use Dielectric::*;
+        use TemperatureCharacteristic::*;
+        match self {
+            C0G | X8G => Ppm { nominal: 0, tolerance: 30 },
+            CH => Ppm { nominal: 0, tolerance: 60 },
+            CJ => Ppm { nominal: 0, tolerance: 120 },
+            CK => Ppm { nominal: 0, tolerance: 250 },
+            UJ | U2J => Ppm { nominal: -750, tolerance: 120 },
+            // +350 to -1000
+            SL => Ppm { nominal: -325, tolerance: 675 },
+            B => Percent { minus: 10, plus: 10 },
+            X5R | X7R | X8R | R => Percent { minus: 15, plus: 15 },
+            X6S | X7S => Percent { minus: 22, plus: 22 },
+            X6T | X7T => Percent { minus: 33, plus: 22 },
+            X7U => Percent { minus: 56, plus: 22 },
+            Y5V => Percent { minus: 82, plus: 22 },
+        }
+    }
+
+    /// Close EIA/JIS counterparts, JIS B is specified from -25°C only
+    pub fn equivalents(&self) -> &'static [Dielectric] {
+        use Dielectric::*;
+        match self {
+            B => &[X5R, X7R],
+            X5R => &[B],
+            R => &[X7R],
+            X7R => &[R, B],
+            CH => &[C0G],
+            C0G => &[CH],
+            UJ => &[U2J],
+            U2J => &[UJ],
+            _ => &[]
+        }
+    }
+
+    /// Lower is more stable: class first, then capacitance change over temperature
+    pub fn stability_rank(&self) -> u16 {
+        let change = match self.temperature_characteristic() {
+            TemperatureCharacteristic::Ppm { nominal, tolerance } => nominal.unsigned_abs() + tolerance,
+            TemperatureCharacteristic::Percent { minus, plus } => minus.max(plus) as u16,
+        };
+        (self.class() as u16) * 10_000 + change
+    }
+
+    /// At least as stable as `original` over at least its temperature range
+    pub fn can_replace(&self, original: &Dielectric) -> bool {
+        let (min, max) = self.temperature_range();
+        let (o_min, o_max) = original.temperature_range();
+        min <= o_min && max >= o_max && (self.class() < original.class()
+            || (self.class() == original.class() && self.stability_rank() <= original.stability_rank()))
+    }
+}
+
+impl fmt::Display for Dielectric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// EIA or JIS name, case insensitive, NP0 is accepted for C0G
+impl FromStr for Dielectric {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Dielectric::*;
+        match s.trim().to_uppercase().as_str() {
+            "SL" => Ok(SL),
+            "CH" => Ok(CH),
+            "CJ" => Ok(CJ),
+            "UJ" => Ok(UJ),
+            "CK" => Ok(CK),
+            "C0G" | "COG" | "NP0" | "NPO" => Ok(C0G),
+            "X8G" => Ok(X8G),
+            "U2J" => Ok(U2J),
+            "B" => Ok(B),
+            "X5R" => Ok(X5R),
+            "X6S" => Ok(X6S),
+            "X6T" => Ok(X6T),
+            "X7R" => Ok(X7R),
+            "X7S" => Ok(X7S),
+            "X7T" => Ok(X7T),
+            "X7U" => Ok(X7U),
+            "X8R" => Ok(X8R),
+            "R" => Ok(R),
+            "Y5V" => Ok(Y5V),
+            _ => Err(Error::WrongDielectricCode)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parts::capacitors::{Capacitance, Capacitor};
+
+    #[test]
+    fn size_codes() {
+        for inch in EIAInchCode::iter() {
+            assert_eq!(EIAInchCode::from_str(&format!("{:#}", inch)).unwrap(), inch);
+        }
+        for metric in IECMetricCode::iter() {
+            assert_eq!(IECMetricCode::from_str(&format!("{:#}", metric)).unwrap().code(), metric.code());
+        }
+        assert_eq!(EIAInchCode::_01005.to_string(), "01005");
+    }
+
+    #[test]
+    fn voltage() {
+        assert_eq!(RatedVoltage::from_str("6V3").unwrap(), RatedVoltage::DC_6V3);
+        assert_eq!(RatedVoltage::from_str("250VAC").unwrap(), RatedVoltage::AC_250V);
+        assert_eq!(RatedVoltage::from_str("3.15 kV").unwrap(), RatedVoltage::DC_3kV15);
+        assert_eq!(RatedVoltage::from_str("1.25V").unwrap(), RatedVoltage::CustomDC(1_250));
+        let required = RatedVoltage::dc(12.0) * 2.0;
+        assert!(!Capacitor::from_str("GRM188R61C106KAALD").unwrap().voltage.at_least(required));
+        assert!(Capacitor::from_str("GRM188R61E106KA73D").unwrap().voltage.at_least(required));
+    }
+
+    #[test]
+    fn tolerance() {
+        let nominal = Capacitance::from_nanofarads(100);
+        for (text, tolerance, display, min, max) in &[
+            ("±5%", Tolerance::PM5, "±5%", 95_000_000, 105_000_000),
+            ("+/-10%", Tolerance::PM10, "±10%", 90_000_000, 110_000_000),
+            ("±0.25%", Tolerance::PM025, "±0.25%", 99_750_000, 100_250_000),
+            ("±0.5%", Tolerance::PM05, "±0.5%", 99_500_000, 100_500_000),
+            ("+100-0%", Tolerance::Percent { minus: 0, plus: 100 }, "+100%", 100_000_000, 200_000_000),
+            ("-0.1+0.2pF", Tolerance::FemtoFarads { minus: 100, plus: 200 }, "-0.1+0.2pF", 99_999_900, 100_000_200)] {
+            let t = Tolerance::from_str(text).unwrap();
+            assert_eq!(t, *tolerance);
+            assert_eq!(t.to_string(), *display);
+            assert_eq!(t.bounds(&nominal), (Capacitance::from_femtofarads(*min), Capacitance::from_femtofarads(*max)));
+        }
+        assert!(Tolerance::from_str("±0.1%").is_err());
+        assert_eq!(eia_tolerance::parse(&nominal, 'W').unwrap(), Tolerance::PM0pF05);
+        assert_eq!(eia_tolerance::encode(&nominal, &Tolerance::PM0pF05).unwrap(), 'W');
+    }
+
+    #[test]
+    fn dielectric() {
+        let c0g = Dielectric::from_str("NP0").unwrap();
+        assert_eq!(c0g, Dielectric::C0G);
+        assert_eq!(c0g.class(), DielectricClass::I);
+        assert_eq!(c0g.stability_rank(), 30);
+        assert!(c0g.can_replace(&Dielectric::X7R));
+        assert!(!Dielectric::B.can_replace(&Dielectric::X7R));
+        assert!(!Dielectric::Y5V.can_replace(&Dielectric::X7R));
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(Length::from_str("0.033in").unwrap().micrometres(), 838);
+        assert_eq!(Length::from_str("33mil").unwrap(), Length::from_mils(33).unwrap());
+        for text in &["10000000000000in", "100000000000000000mil", "18446744073709551615in"] {
+            assert!(matches!(Length::from_str(text), Err(Error::WrongLength)), "{}", text);
+        }
+        assert_eq!(Length::from_mils(u32::MAX / 254).unwrap().micrometres(), (u32::MAX / 254 * 254 + 5) / 10);
+        assert!(matches!(Length::from_mils(u32::MAX), Err(Error::WrongLength)));
+        let l = Length::from_str("0.85mm").unwrap();
+        assert_eq!(l, Length::from_str("850um").unwrap());
+        assert_eq!(l.to_string(), "0.85mm");
+        assert_eq!(format!("{:#}", l), "0.033in");
+        let height = Capacitor::from_str("GRM188R71H104KA93D").unwrap().max_height;
+        assert_eq!(height.length(), Some(Length::from_micrometres(800)));
+    }
+}
@@ -0,0 +1,192 @@
+use crate::parts::{EIAInchCode, IECMetricCode, Length, SizeSystem, split_size_code, capacitors::{Capacitor, Error}};
+use std::str::FromStr;
+
+/// Body outline of a two terminal chip, nominal length and width with their ± tolerances and the
+/// thickness range of catalogue parts in this size
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BodySize {
+    pub length: Length,
+    pub length_tolerance: Length,
+    pub width: Length,
+    pub width_tolerance: Length,
+    pub min_thickness: Length,
+    pub max_thickness: Length,
+}
+
+impl BodySize {
+    pub fn max_length(&self) -> Length {
+        Length::from_micrometres(self.length.micrometres() + self.length_tolerance.micrometres())
+    }
+
+    pub fn max_width(&self) -> Length {
+        Length::from_micrometres(self.width.micrometres() + self.width_tolerance.micrometres())
+    }
+
+    /// Board area covered by the largest body in µm²
+    pub fn max_area(&self) -> u64 {
+        self.max_length().micrometres() as u64 * self.max_width().micrometres() as u64
+    }
+}
+
+/// All values in micrometres
+const fn size(length: u32, length_tolerance: u32, width: u32, width_tolerance: u32, min_thickness: u32, max_thickness: u32) -> BodySize {
+    BodySize {
+        length: Length::from_micrometres(length),
+        length_tolerance: Length::from_micrometres(length_tolerance),
+        width: Length::from_micrometres(width),
+        width_tolerance: Length::from_micrometres(width_tolerance),
+        min_thickness: Length::from_micrometres(min_thickness),
+        max_thickness: Length::from_micrometres(max_thickness),
+    }
+}
+
+#[allow(clippy::just_underscores_and_digits)]
+impl IECMetricCode {
+    /// Nominal body size, tolerances follow the common MLCC catalogue values
+    pub fn body_size(&self) -> BodySize {
+        use IECMetricCode::*;
+        match self {
+            _0201 => size(250, 13, 125, 13, 100, 150),
+            _03015 => size(300, 20, 150, 20, 100, 200),
+            _0402 => size(400, 20, 200, 20, 200, 220),
+            _0404 => size(400, 20, 400, 20, 200, 400),
+            _0505 => size(500, 50, 500, 50, 200, 500),
+            _0603 => size(600, 30, 300, 30, 300, 330),
+            // reverse geometry, terminals on the long sides
+            _0610 => size(600, 50, 1000, 50, 300, 500),
+            _0805 => size(800, 50, 500, 50, 300, 500),
+            _0808 => size(800, 50, 800, 50, 300, 800),
+            _1005 => size(1000, 50, 500, 50, 200, 550),
+            _1310 => size(1250, 100, 1000, 100, 300, 850),
+            _1608 => size(1600, 100, 800, 100, 300, 950),
+            _2012 => size(2000, 100, 1250, 100, 600, 1450),
+            _2520 => size(2500, 200, 2000, 200, 600, 2200),
+            _2828 => size(2800, 200, 2800, 200, 600, 2800),
+            _3216 => size(3200, 200, 1600, 200, 600, 1800),
+            _3225 => size(3200, 300, 2500, 200, 850, 2800),
+            _3625 => size(3600, 300, 2500, 200, 850, 2800),
+            _3838 => size(3800, 300, 3800, 300, 850, 3000),
+            _4516 => size(4500, 300, 1600, 200, 850, 1800),
+            _4520 => size(4500, 300, 2000, 200, 850, 2200),
+            _4532 => size(4500, 400, 3200, 300, 850, 2800),
+            _4564 => size(4500, 400, 6400, 400, 850, 2800),
+            _5025 => size(5000, 300, 2500, 200, 850, 2800),
+            _5050 => size(5000, 400, 5000, 400, 850, 2800),
+            _5664 => size(5600, 400, 6400, 400, 1000, 2800),
+            _5728 => size(5700, 400, 2800, 300, 1000, 2800),
+            _5750 => size(5700, 400, 5000, 400, 1000, 2800),
+            _5764 => size(5700, 400, 6400, 400, 1000, 2800),
+            _6332 => size(6300, 400, 3200, 300, 1000, 2800),
+            _6432 => size(6400, 400, 3200, 300, 1000, 2800),
+            _6450 => size(6400, 400, 5000, 400, 1000, 3200),
+            _7450 => size(7400, 400, 5000, 400, 1000, 3200),
+            _8484 => size(8400, 500, 8400, 500, 1000, 3500),
+            _9210 => size(9200, 500, 10200, 500, 1000, 3500),
+            _100100 => size(10200, 500, 10200, 500, 1000, 3500),
+            _140127 => size(14000, 500, 12700, 500, 1000, 4000),
+            _203153 => size(20300, 500, 15300, 500, 1000, 4000),
+        }
+    }
+}
+
+impl EIAInchCode {
+    /// Body size of the metric equivalent, see [IECMetricCode::body_size]. Codes with two metric
+    /// readings take the smaller body, 5664 for 2225 and 6332 for 2512, as the KiCad footprints do.
+    pub fn body_size(&self) -> BodySize {
+        match self.metric_candidates().first() {
+            Some(metric) => metric.body_size(),
+            None => IECMetricCode::from(*self).body_size(),
+        }
+    }
+}
+
+/// What is known about a BOM line besides its size code
+#[derive(Debug, Copy, Clone)]
+pub enum SizeHint<'a> {
+    /// Manufacturer part number, the size is taken from the decoded part
+    PartNumber(&'a str),
+    /// Measured or datasheet body length and width
+    BodySize(Length, Length),
+    None,
+}
+
+/// Every size a code can stand for: 0603 is 0603 Inch or 0603 Metric (0201 Inch), 0603 Inch is
+/// only the former
+pub fn size_candidates(code: &str) -> Result<Vec<EIAInchCode>, Error> {
+    let mut candidates = Vec::new();
+    let (digits, system) = split_size_code(code);
+    if system != Some(SizeSystem::Metric) {
+        candidates.extend(EIAInchCode::from_str(&format!("{} Inch", digits)).ok());
+    }
+    if system != Some(SizeSystem::Inch) {
+        candidates.extend(IECMetricCode::from_str(&format!("{}M", digits)).ok().map(EIAInchCode::from));
+    }
+    candidates.dedup();
+    if candidates.is_empty() {
+        return Err(Error::WrongDimensionCode);
+    }
+    Ok(candidates)
+}
+
+/// Reads a size code that may lack its unit, using `hint` to pick between the inch and metric
+/// readings. Body dimensions must be within the nominal size tolerance of exactly one reading.
+pub fn resolve_size(code: &str, hint: SizeHint) -> Result<EIAInchCode, Error> {
+    let candidates = size_candidates(code)?;
+    if let [size] = candidates.as_slice() {
+        return Ok(*size);
+    }
+    let matching: Vec<EIAInchCode> = match hint {
+        SizeHint::PartNumber(part_number) => {
+            let size = Capacitor::from_str(part_number)?.dimensions;
+            return if candidates.contains(&size) { Ok(size) } else { Err(Error::WrongDimensionCode) };
+        },
+        SizeHint::BodySize(length, width) => candidates.into_iter()
+            .filter(|size| {
+                let body = size.body_size();
+                within(length, body.length, body.length_tolerance) && within(width, body.width, body.width_tolerance)
+            })
+            .collect(),
+        SizeHint::None => candidates
+    };
+    match matching.as_slice() {
+        [size] => Ok(*size),
+        _ => Err(Error::AmbiguousSize)
+    }
+}
+
+fn within(value: Length, nominal: Length, tolerance: Length) -> bool {
+    value.micrometres().abs_diff(nominal.micrometres()) <= tolerance.micrometres()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn body() {
+        for text in &["0603", "1608M", "0603 Inch"] {
+            let body = EIAInchCode::from_str(text).unwrap().body_size();
+            assert_eq!((body.length, body.width), (Length::from_micrometres(1600), Length::from_micrometres(800)));
+            assert_eq!(body.length_tolerance, Length::from_micrometres(100));
+            assert_eq!((body.min_thickness, body.max_thickness), (Length::from_micrometres(300), Length::from_micrometres(950)));
+        }
+        assert_eq!(IECMetricCode::from_str("2012 Metric").unwrap().body_size(), EIAInchCode::_0805.body_size());
+    }
+
+    #[test]
+    fn ambiguous_codes() {
+        assert_eq!(EIAInchCode::_2225.metric_candidates(), [IECMetricCode::_5664, IECMetricCode::_5764]);
+        assert!(matches!(EIAInchCode::_2225.to_metric(), Err(Error::AmbiguousSize)));
+        assert!(matches!(IECMetricCode::from_str("2225"), Err(Error::AmbiguousSize)));
+        assert!(matches!(IECMetricCode::from_str("2225 Inch"), Err(Error::AmbiguousSize)));
+        assert_eq!(IECMetricCode::from_str("1206").unwrap(), IECMetricCode::_3216);
+        assert_eq!(EIAInchCode::_2225.body_size(), IECMetricCode::_5664.body_size());
+        assert_eq!(EIAInchCode::_2512.body_size(), IECMetricCode::_6332.body_size());
+        assert_eq!(size_candidates("0603").unwrap(), [EIAInchCode::_0603, EIAInchCode::_0201]);
+        assert!(matches!(resolve_size("0603", SizeHint::None), Err(Error::AmbiguousSize)));
+        assert_eq!(resolve_size("0603", SizeHint::PartNumber("GRM033R71C104KE14D")).unwrap(), EIAInchCode::_0201);
+        assert_eq!(resolve_size("0603", SizeHint::PartNumber("GRM188R71H104KA93D")).unwrap(), EIAInchCode::_0603);
+        let body = SizeHint::BodySize(Length::from_micrometres(1580), Length::from_micrometres(820));
+        assert_eq!(resolve_size("0603", body).unwrap(), EIAInchCode::_0603);
+    }
+}